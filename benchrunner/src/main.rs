@@ -0,0 +1,221 @@
+mod metrics;
+mod sink;
+mod window;
+
+use std::fs;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use cggmp21::security_level::SecurityLevel128;
+use cggmp21::supported_curves::Secp256k1;
+use cggmp21::KeyShare;
+use clap::Parser;
+use log::{error, info};
+use mpc_client::{KeyShareData, Signer, SignerConfig};
+use proto::mpc::Chain;
+use rand::RngCore;
+
+use metrics::SignMetric;
+use sink::{MetricSink, PostgresSink, StdoutJsonSink};
+use window::LatencyWindow;
+
+/// Long-running end-to-end signing latency runner.
+///
+/// Repeatedly signs random payloads against a live participant/sign-gateway
+/// deployment at a fixed rate, recording p50/p95/p99 latency and
+/// success/error counts over a sliding window so CI or a dashboard can
+/// track signing performance regressions over time.
+#[derive(Parser, Debug)]
+#[command(name = "benchrunner")]
+#[command(about = "Drive end-to-end MPC signing latency and record metrics", long_about = None)]
+struct Args {
+    /// Host of the sign-gateway (or mock participant standing in for it)
+    #[arg(long, default_value = "127.0.0.1")]
+    sign_gateway_host: String,
+
+    /// Port of the sign-gateway
+    #[arg(long, default_value_t = 50051)]
+    sign_gateway_port: u16,
+
+    /// Host of the SSE relay
+    #[arg(long, default_value = "127.0.0.1")]
+    sse_host: String,
+
+    /// Port of the SSE relay
+    #[arg(long, default_value_t = 8080)]
+    sse_port: u16,
+
+    /// Host the local participant server binds to
+    #[arg(long, default_value = "127.0.0.1")]
+    local_host: String,
+
+    /// Port the local participant server binds to
+    #[arg(long, default_value_t = 50052)]
+    local_port: u16,
+
+    /// Signing threshold
+    #[arg(long, default_value_t = 2)]
+    threshold: u16,
+
+    /// Total number of participants
+    #[arg(long, default_value_t = 2)]
+    total_participants: u16,
+
+    /// Host the signer's Prometheus `/metrics` endpoint binds to
+    #[arg(long, default_value = "127.0.0.1")]
+    metrics_host: String,
+
+    /// Port the signer's Prometheus `/metrics` endpoint listens on
+    #[arg(long, default_value_t = 9090)]
+    metrics_port: u16,
+
+    /// Account ID to sign with
+    #[arg(long)]
+    account_id: String,
+
+    /// Path to the local participant's key share JSON file (as produced by
+    /// `key-gen`)
+    #[arg(long)]
+    key_share_file: String,
+
+    /// Signing rate, in requests per second
+    #[arg(short, long, default_value_t = 1.0)]
+    rate: f64,
+
+    /// How long to run for
+    #[arg(long, default_value_t = 60)]
+    duration_secs: u64,
+
+    /// Payload size to sign, in bytes
+    #[arg(long, default_value_t = 32)]
+    payload_bytes: usize,
+
+    /// Number of recent attempts the rolling p50/p95/p99 is computed over
+    #[arg(long, default_value_t = 100)]
+    window_size: usize,
+
+    /// Optional Postgres connection string; when set, metrics are also
+    /// inserted into `--postgres-table` there in addition to stdout
+    #[arg(long)]
+    postgres_url: Option<String>,
+
+    /// Postgres table metrics are written to
+    #[arg(long, default_value = "mpc_sign_metrics")]
+    postgres_table: String,
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is after the epoch")
+        .as_millis() as i64
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+
+    let args = Args::parse();
+    let run_id = uuid::Uuid::new_v4();
+    info!("Starting benchrunner, run_id={}", run_id);
+
+    let key_share_json = fs::read_to_string(&args.key_share_file)
+        .with_context(|| format!("failed to read key share file: {}", args.key_share_file))?;
+    let key_share: KeyShare<Secp256k1, SecurityLevel128> = serde_json::from_str(&key_share_json)
+        .context("failed to parse key share file")?;
+    let local_participant_index = key_share.core.i;
+
+    let signer_config = SignerConfig {
+        local_participant_host: args.local_host.clone(),
+        local_participant_port: args.local_port,
+        local_participant_index,
+        key_shares: vec![KeyShareData {
+            account_id: args.account_id.clone(),
+            key_share_data: key_share_json,
+        }],
+        sign_gateway_host: args.sign_gateway_host.clone(),
+        sign_gateway_port: args.sign_gateway_port,
+        sse_host: args.sse_host.clone(),
+        sse_port: args.sse_port,
+        threshold: args.threshold,
+        total_participants: args.total_participants,
+        log_level: "info".to_string(),
+        connectivity_check_interval_secs: mpc_client::DEFAULT_CONNECTIVITY_CHECK_INTERVAL_SECS,
+        metrics_host: args.metrics_host.clone(),
+        metrics_port: args.metrics_port,
+        tls: None,
+        proxy: None,
+    };
+
+    let mut signer = Signer::new(signer_config, tokio::runtime::Handle::current())
+        .await
+        .context("failed to create signer")?;
+    signer
+        .start_local_participant()
+        .await
+        .context("failed to start local participant")?;
+
+    let mut sinks: Vec<Box<dyn MetricSink>> = vec![Box::new(StdoutJsonSink)];
+    if let Some(url) = &args.postgres_url {
+        let postgres_sink = PostgresSink::connect(url, &args.postgres_table)
+            .await
+            .context("failed to set up Postgres metrics sink")?;
+        sinks.push(Box::new(postgres_sink));
+    }
+
+    let mut window = LatencyWindow::new(args.window_size);
+    let mut ticker = tokio::time::interval(Duration::from_secs_f64((1.0 / args.rate.max(0.001)).max(0.001)));
+    let deadline = Instant::now() + Duration::from_secs(args.duration_secs);
+
+    while Instant::now() < deadline {
+        ticker.tick().await;
+
+        let mut data = vec![0u8; args.payload_bytes];
+        rand::thread_rng().fill_bytes(&mut data);
+
+        let started_at = Instant::now();
+        let result = signer.sign(data, args.account_id.clone(), Chain::Ethereum).await;
+        let latency_ms = started_at.elapsed().as_secs_f64() * 1000.0;
+
+        let outcome = match &result {
+            Ok(_) => "success".to_string(),
+            Err(e) => {
+                error!("Sign attempt failed: {}", e);
+                format!("error: {e}")
+            }
+        };
+        window.push(latency_ms, result.is_ok());
+
+        let metric = SignMetric {
+            run_id,
+            timestamp_ms: now_ms(),
+            payload_bytes: args.payload_bytes as i64,
+            latency_ms,
+            outcome,
+        };
+
+        for metric_sink in &mut sinks {
+            if let Err(e) = metric_sink.record(&metric).await {
+                error!("Metric sink failed to record attempt: {}", e);
+            }
+        }
+
+        if window.len() == args.window_size {
+            info!(
+                "window stats (last {}): p50={:.1}ms p95={:.1}ms p99={:.1}ms success={} error={}",
+                args.window_size,
+                window.percentile(50.0).unwrap_or(0.0),
+                window.percentile(95.0).unwrap_or(0.0),
+                window.percentile(99.0).unwrap_or(0.0),
+                window.successes,
+                window.errors,
+            );
+        }
+    }
+
+    info!(
+        "Run {} complete: {} success, {} error (final window)",
+        run_id, window.successes, window.errors
+    );
+    Ok(())
+}
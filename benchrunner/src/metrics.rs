@@ -0,0 +1,32 @@
+use serde::Serialize;
+use tokio_postgres::types::ToSql;
+use uuid::Uuid;
+
+/// One sign attempt's outcome, in the shape every sink writes — whether
+/// that's a line of stdout JSON or a row in the Postgres metrics table.
+#[derive(Debug, Clone, Serialize)]
+pub struct SignMetric {
+    pub run_id: Uuid,
+    pub timestamp_ms: i64,
+    pub payload_bytes: i64,
+    pub latency_ms: f64,
+    pub outcome: String,
+}
+
+impl SignMetric {
+    /// Column names, in the same order as [`SignMetric::row_params`].
+    /// Written by hand rather than via a derive macro since this crate
+    /// doesn't otherwise pull in one.
+    pub const COLUMNS: [&'static str; 5] =
+        ["run_id", "timestamp_ms", "payload_bytes", "latency_ms", "outcome"];
+
+    pub fn row_params(&self) -> [&(dyn ToSql + Sync); 5] {
+        [
+            &self.run_id,
+            &self.timestamp_ms,
+            &self.payload_bytes,
+            &self.latency_ms,
+            &self.outcome,
+        ]
+    }
+}
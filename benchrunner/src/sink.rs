@@ -0,0 +1,85 @@
+use anyhow::{Context, Result};
+use tokio_postgres::NoTls;
+
+use crate::metrics::SignMetric;
+
+/// Where completed [`SignMetric`] rows are durably written. Implementations
+/// are called once per sign attempt, so `record` should not block the
+/// signing loop for long.
+#[tonic::async_trait]
+pub trait MetricSink: Send {
+    async fn record(&mut self, metric: &SignMetric) -> Result<()>;
+}
+
+/// Default sink: one JSON object per line on stdout, for piping into a log
+/// aggregator, `jq`, or a file.
+pub struct StdoutJsonSink;
+
+#[tonic::async_trait]
+impl MetricSink for StdoutJsonSink {
+    async fn record(&mut self, metric: &SignMetric) -> Result<()> {
+        println!("{}", serde_json::to_string(metric).context("failed to serialize metric")?);
+        Ok(())
+    }
+}
+
+/// Appends each metric as a row in a Postgres table, creating the table on
+/// first use if it doesn't already exist.
+pub struct PostgresSink {
+    client: tokio_postgres::Client,
+    table: String,
+}
+
+impl PostgresSink {
+    pub async fn connect(url: &str, table: &str) -> Result<Self> {
+        let (client, connection) = tokio_postgres::connect(url, NoTls)
+            .await
+            .context("failed to connect to Postgres metrics sink")?;
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                log::error!("Postgres metrics connection closed: {}", e);
+            }
+        });
+
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {table} (
+                    run_id UUID NOT NULL,
+                    timestamp_ms BIGINT NOT NULL,
+                    payload_bytes BIGINT NOT NULL,
+                    latency_ms DOUBLE PRECISION NOT NULL,
+                    outcome TEXT NOT NULL
+                )"
+            ))
+            .await
+            .context("failed to create metrics table")?;
+
+        Ok(Self {
+            client,
+            table: table.to_string(),
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl MetricSink for PostgresSink {
+    async fn record(&mut self, metric: &SignMetric) -> Result<()> {
+        let placeholders = (1..=SignMetric::COLUMNS.len())
+            .map(|i| format!("${i}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.table,
+            SignMetric::COLUMNS.join(", "),
+            placeholders
+        );
+
+        self.client
+            .execute(&query, &metric.row_params())
+            .await
+            .context("failed to insert metric row")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::VecDeque;
+
+/// Fixed-capacity ring of recent latencies used to compute rolling
+/// p50/p95/p99 and success/error counts without retaining the whole run's
+/// history.
+pub struct LatencyWindow {
+    capacity: usize,
+    samples: VecDeque<f64>,
+    pub successes: u64,
+    pub errors: u64,
+}
+
+impl LatencyWindow {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            samples: VecDeque::with_capacity(capacity.max(1)),
+            successes: 0,
+            errors: 0,
+        }
+    }
+
+    pub fn push(&mut self, latency_ms: f64, success: bool) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency_ms);
+
+        if success {
+            self.successes += 1;
+        } else {
+            self.errors += 1;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Linear-interpolation-free percentile (nearest-rank) over the samples
+    /// currently in the window.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).expect("latencies are never NaN"));
+        let rank = ((p / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+        Some(sorted[rank.min(sorted.len() - 1)])
+    }
+}
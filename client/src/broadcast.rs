@@ -0,0 +1,127 @@
+//! Broadcasts a signed EIP-2718 transaction and tracks it to resolution.
+//!
+//! Split the way Serai's Eventuality handling is: a [`Claim`] is just the
+//! hash of the transaction being watched for, and [`broadcast_and_confirm`]
+//! polls successive blocks, reading chain state at each one, until the claim
+//! resolves - instead of the "fire once and hope" demo path this replaces.
+
+use std::time::Duration;
+
+use alloy::primitives::{Address, TxHash};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionReceipt;
+use alloy_consensus::private::alloy_eips::Encodable2718;
+use alloy_consensus::TxEnvelope;
+use anyhow::{Context, Result};
+
+/// How many blocks to watch with no sign of the transaction in the mempool
+/// or a receipt before assuming it was dropped and re-broadcasting it.
+const DROPPED_AFTER_BLOCKS: u64 = 3;
+
+/// How many blocks total to watch a claim for before giving up with
+/// [`Resolution::TimedOut`].
+const MAX_BLOCKS_TO_WATCH: u64 = 50;
+
+/// How long to sleep between polls when no new block has landed yet.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A transaction hash being watched for resolution - the same role Serai's
+/// `Claim` plays for its Eventuality tracking.
+#[derive(Debug, Clone, Copy)]
+pub struct Claim {
+    pub tx_hash: TxHash,
+}
+
+/// Outcome of watching a [`Claim`] to resolution.
+#[derive(Debug)]
+pub enum Resolution {
+    /// Mined and buried under at least the requested confirmation depth.
+    Confirmed(TransactionReceipt),
+    /// Never reappeared after a re-broadcast, and the account's confirmed
+    /// nonce has since moved past the one this transaction used - something
+    /// else (most likely a fee-bumped replacement) claimed it instead.
+    Replaced,
+    /// Neither confirmed nor observably replaced within
+    /// `MAX_BLOCKS_TO_WATCH` blocks; the caller should decide whether to
+    /// re-sign with a bumped fee.
+    TimedOut,
+}
+
+/// Sends `envelope`'s EIP-2718 bytes via `provider.send_raw_transaction`,
+/// records the resulting hash as a [`Claim`], then polls successive blocks
+/// until the claim is buried under `confirmations` blocks of depth,
+/// re-broadcasting once if it vanishes from the mempool for
+/// `DROPPED_AFTER_BLOCKS` blocks, and giving up after `MAX_BLOCKS_TO_WATCH`.
+///
+/// `from`/`nonce` are the sender and nonce the transaction was signed with,
+/// used only to detect the dropped-and-replaced case.
+pub async fn broadcast_and_confirm(
+    provider: &impl Provider,
+    envelope: &TxEnvelope,
+    from: Address,
+    nonce: u64,
+    confirmations: u64,
+) -> Result<Resolution> {
+    let raw = envelope.encoded_2718();
+    let pending = provider
+        .send_raw_transaction(&raw)
+        .await
+        .context("failed to broadcast transaction")?;
+    let claim = Claim { tx_hash: *pending.tx_hash() };
+
+    let start_block = provider
+        .get_block_number()
+        .await
+        .context("failed to read starting block number")?;
+    let mut rebroadcast_done = false;
+    let mut blocks_since_seen = 0u64;
+
+    loop {
+        let latest = provider
+            .get_block_number()
+            .await
+            .context("failed to read latest block number")?;
+
+        if latest.saturating_sub(start_block) > MAX_BLOCKS_TO_WATCH {
+            return Ok(Resolution::TimedOut);
+        }
+
+        match provider
+            .get_transaction_receipt(claim.tx_hash)
+            .await
+            .context("failed to poll for transaction receipt")?
+        {
+            Some(receipt) => {
+                let mined_at = receipt.block_number.unwrap_or(latest);
+                let depth = latest.saturating_sub(mined_at) + 1;
+                if depth >= confirmations {
+                    return Ok(Resolution::Confirmed(receipt));
+                }
+            }
+            None => {
+                blocks_since_seen += 1;
+                if blocks_since_seen >= DROPPED_AFTER_BLOCKS {
+                    let confirmed_nonce = provider
+                        .get_transaction_count(from)
+                        .await
+                        .context("failed to read account nonce while checking for replacement")?;
+                    if confirmed_nonce > nonce {
+                        return Ok(Resolution::Replaced);
+                    }
+
+                    if !rebroadcast_done {
+                        // Best-effort: the node may reject this as a known
+                        // transaction if it's still sitting in the mempool
+                        // after all, which is fine - we're only trying to
+                        // recover from it actually having been dropped.
+                        let _ = provider.send_raw_transaction(&raw).await;
+                        rebroadcast_done = true;
+                        blocks_since_seen = 0;
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,87 @@
+//! Layered configuration: `load_mpc_config` parses the YAML file, then
+//! [`apply_overrides`] overlays environment-variable overrides on top of the
+//! parsed values and validates cross-field invariants, so the same binary
+//! can target mainnet/testnet or a different threshold without recompiling
+//! the hardcoded constants `run_mpc_signing_test` used to carry.
+
+use std::env;
+
+use mpc_client::MpcConfig;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Invalid environment variable {name}: {reason}")]
+    InvalidEnvVar { name: String, reason: String },
+    #[error("Invalid configuration: {0}")]
+    Invariant(String),
+}
+
+/// Runtime transaction parameters `run_mpc_signing_test` needs beyond
+/// `MpcConfig` itself - the RPC endpoint, chain id, and fee parameter that
+/// used to be hardcoded constants inside it.
+#[derive(Debug, Clone)]
+pub struct TxParams {
+    pub rpc_url: String,
+    pub chain_id: u64,
+    pub max_priority_fee_per_gas: u64,
+}
+
+impl Default for TxParams {
+    fn default() -> Self {
+        Self {
+            rpc_url: "https://tiniest-clean-sponge.base-sepolia.quiknode.pro/5380b34bde82bd24e05443cbe7f3efce0625d89e".to_string(),
+            chain_id: 84532, // Base Sepolia
+            max_priority_fee_per_gas: 1_000_000_000, // 1 Gwei
+        }
+    }
+}
+
+/// Overlays recognized environment variables on top of `mpc`/`tx_params`
+/// (already parsed from YAML and defaults, respectively), then validates
+/// invariants that must hold regardless of where each value came from.
+///
+/// Recognized overrides: `MPC_THRESHOLD`, `SIGN_SERVICE_HOST`, `RPC_URL`,
+/// `CHAIN_ID`, `MAX_PRIORITY_FEE_PER_GAS`.
+pub fn apply_overrides(mpc: &mut MpcConfig, tx_params: &mut TxParams) -> Result<(), ConfigError> {
+    if let Ok(value) = env::var("MPC_THRESHOLD") {
+        mpc.threshold = value.parse().map_err(|_| ConfigError::InvalidEnvVar {
+            name: "MPC_THRESHOLD".to_string(),
+            reason: "expected a u16".to_string(),
+        })?;
+    }
+
+    if let Ok(value) = env::var("SIGN_SERVICE_HOST") {
+        mpc.sign_service_host = value;
+    }
+
+    if let Ok(value) = env::var("RPC_URL") {
+        tx_params.rpc_url = value;
+    }
+
+    if let Ok(value) = env::var("CHAIN_ID") {
+        tx_params.chain_id = value.parse().map_err(|_| ConfigError::InvalidEnvVar {
+            name: "CHAIN_ID".to_string(),
+            reason: "expected a u64".to_string(),
+        })?;
+    }
+
+    if let Ok(value) = env::var("MAX_PRIORITY_FEE_PER_GAS") {
+        tx_params.max_priority_fee_per_gas = value.parse().map_err(|_| ConfigError::InvalidEnvVar {
+            name: "MAX_PRIORITY_FEE_PER_GAS".to_string(),
+            reason: "expected a u64".to_string(),
+        })?;
+    }
+
+    if mpc.key_shares.is_empty() {
+        return Err(ConfigError::Invariant("key_shares must not be empty".to_string()));
+    }
+    if mpc.threshold > mpc.total_participants {
+        return Err(ConfigError::Invariant(format!(
+            "threshold ({}) must not exceed total_participants ({})",
+            mpc.threshold, mpc.total_participants
+        )));
+    }
+
+    Ok(())
+}
@@ -9,6 +9,13 @@ use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
 
 use mpc_client::{MpcSigner, MpcConfig, KeyShare};
 
+mod broadcast;
+mod config;
+mod scheduler;
+mod sender_guard;
+use config::TxParams;
+use scheduler::AccountScheduler;
+
 /// Load client configuration from YAML file and convert to MpcConfig
 fn load_mpc_config(config_path: &str) -> Result<MpcConfig> {
     let yaml_content = fs::read_to_string(config_path)
@@ -147,7 +154,12 @@ fn recover_public_key(message_hash: &[u8], r: &[u8], s: &[u8], recovery_id: u32)
 
 /// Run a complete MPC signing test: initialize -> sign -> shutdown
 /// This function can be called multiple times to test repeated initialization
-async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result<()> {
+async fn run_mpc_signing_test(
+    mpc_config: MpcConfig,
+    test_number: u32,
+    scheduler: &AccountScheduler,
+    tx_params: &TxParams,
+) -> Result<()> {
     println!("\n{}", "=".repeat(60));
     println!("üîÑ Test Run #{}", test_number);
     println!("{}", "=".repeat(60));
@@ -157,6 +169,15 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
         .map(|ks| ks.account_id.clone())
         .ok_or_else(|| anyhow::anyhow!("No key shares available"))?;
 
+    // Derive the address this account's share is supposed to control, so
+    // the recovered signer can be checked against it after signing instead
+    // of only ever logging a public key nobody compares to anything.
+    let expected_address = mpc_config.key_shares.iter()
+        .find(|ks| ks.account_id == account_id)
+        .ok_or_else(|| anyhow::anyhow!("Account ID '{}' not found in key shares", account_id))?
+        .eth_address()
+        .with_context(|| format!("Failed to derive expected address for account_id {}", account_id))?;
+
     // Step 1: Initialize MpcSigner
     println!("\n[1/3] üöÄ Initializing MpcSigner...");
     let signer = match MpcSigner::new(mpc_config) {
@@ -184,11 +205,12 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
     // Step 2: Create and Sign Transaction
     println!("\n[2/3] üîê Creating and Signing Transaction...");
 
-    // Setup Base Sepolia RPC connection
-    let rpc_url = "https://tiniest-clean-sponge.base-sepolia.quiknode.pro/5380b34bde82bd24e05443cbe7f3efce0625d89e";
-    let chain_id: u64 = 84532; // Base Sepolia chain ID
+    // RPC connection; overridable via `RPC_URL`/`CHAIN_ID` instead of being
+    // hardcoded to Base Sepolia (see `config::apply_overrides`).
+    let rpc_url = tx_params.rpc_url.as_str();
+    let chain_id = tx_params.chain_id;
 
-    println!("üåê Connecting to Base Sepolia (Chain ID: {})", chain_id);
+    println!("üåê Connecting to chain (Chain ID: {})", chain_id);
 
     let provider = ProviderBuilder::new()
         .connect_http(rpc_url.parse().expect("Invalid RPC URL"));
@@ -224,8 +246,9 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
     let gas_limit = 21_000u64; // Basic transfer gas limit
     let data = Bytes::new(); // Empty data, simple transfer
 
-    // Get current base fee and construct EIP-1559 fee parameters
-    let max_priority_fee_per_gas = 1_000_000_000u64; // 1 Gwei priority fee
+    // Get current base fee and construct EIP-1559 fee parameters; overridable
+    // via `MAX_PRIORITY_FEE_PER_GAS` (see `config::apply_overrides`).
+    let max_priority_fee_per_gas = tx_params.max_priority_fee_per_gas;
     let max_fee_per_gas = match provider.get_gas_price().await {
         Ok(price) => {
             let base_fee = price as u64;
@@ -241,8 +264,10 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
         }
     };
 
-    // For demo purposes, use incremental nonce based on test number
-    let nonce = test_number as u64;
+    // Reserve this account's next nonce so repeated/concurrent test runs
+    // never reuse or skip one, instead of faking it from the test number.
+    let nonce = scheduler.reserve(&provider, &account_id, expected_address).await
+        .with_context(|| format!("Failed to reserve nonce for account_id {}", account_id))?;
 
     info!("EIP-1559 Transaction details:");
     info!("  To: {}", to_address);
@@ -270,7 +295,27 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
 
     info!("Transaction signing hash: 0x{}", hex::encode(&signing_hash_bytes));
 
-    println!("üîê Executing MPC Signature (Threshold 2/3)...");
+    // EIP-3607 guard: refuse to sign from an address that turns out to have
+    // deployed contract code, since nothing else in this path checks the
+    // key share's pubkey-to-address mapping against chain state.
+    if let Err(e) = sender_guard::assert_eoa(&provider, expected_address).await {
+        error!("Sender guard rejected account_id {}: {}", account_id, e);
+
+        // The reserved nonce was never used, so free it for reuse instead
+        // of burning a gap.
+        scheduler.release(&account_id, nonce).await;
+
+        let _ = signer.shutdown();
+        tokio::task::spawn_blocking(move || {
+            drop(signer);
+        })
+        .await
+        .ok();
+
+        return Err(e.into());
+    }
+
+    println!("üîê Executing MPC Signature (Threshold 2/3)...");
 
     // Execute MPC signature with account_id
     match signer.sign_data(signing_hash_bytes.clone(), account_id.clone()) {
@@ -282,13 +327,63 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
             // For EIP-1559, we use y_parity (0 or 1) instead of v
             let y_parity = signature.v;
 
-            // Recover public key from signature for verification
+            // Recover public key from signature for verification, and
+            // assert the address it maps to is the one we meant to sign
+            // with -- otherwise a wrong (or wrongly-indexed) key share would
+            // produce a signature that looks fine but silently speaks for
+            // the wrong account.
             match recover_public_key(&signing_hash_bytes, &signature.r, &signature.s, y_parity) {
-                Ok((compressed, _uncompressed)) => {
+                Ok((compressed, uncompressed)) => {
                     info!("Public Key (compressed): 0x{}", compressed);
+
+                    let uncompressed_bytes = hex::decode(&uncompressed)
+                        .map_err(|e| anyhow::anyhow!("Failed to decode recovered public key: {}", e))?;
+                    let recovered_address = Address::from_slice(
+                        &alloy::primitives::keccak256(&uncompressed_bytes[1..])[12..],
+                    );
+
+                    if recovered_address != expected_address {
+                        error!(
+                            "Recovered signer address {} does not match expected address {} for account_id {}",
+                            recovered_address, expected_address, account_id
+                        );
+
+                        // The reserved nonce was never used, so free it for
+                        // reuse instead of burning a gap.
+                        scheduler.release(&account_id, nonce).await;
+
+                        // Cleanup in blocking context before returning error
+                        let _ = signer.shutdown();
+                        tokio::task::spawn_blocking(move || {
+                            drop(signer);
+                        })
+                        .await
+                        .ok();
+
+                        return Err(anyhow::anyhow!(
+                            "signature recovers to {} but account_id {} should sign as {}",
+                            recovered_address, account_id, expected_address
+                        ));
+                    }
+
+                    info!("Recovered signer address {} matches expected address", recovered_address);
                 }
                 Err(e) => {
                     error!("Public Key Recovery Failed: {}", e);
+
+                    // The reserved nonce was never used, so free it for
+                    // reuse instead of burning a gap.
+                    scheduler.release(&account_id, nonce).await;
+
+                    // Cleanup in blocking context before returning error
+                    let _ = signer.shutdown();
+                    tokio::task::spawn_blocking(move || {
+                        drop(signer);
+                    })
+                    .await
+                    .ok();
+
+                    return Err(e);
                 }
             }
 
@@ -308,16 +403,56 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
             println!("üì¶ Signed Transaction: {} bytes (type 0x{:02x})",
                      encoded.len(), encoded.get(0).unwrap_or(&0));
 
-            // Note: We don't broadcast in test mode to avoid nonce conflicts
-            println!("‚ÑπÔ∏è  Broadcasting skipped in test mode");
-            info!("Transaction would be sent to: {}", to_address);
+            // Broadcasting is opt-in (`BROADCAST_TX=1`): this demo targets a
+            // live testnet, and most runs just want to exercise signing.
+            let should_broadcast = std::env::var("BROADCAST_TX")
+                .map(|v| v == "1")
+                .unwrap_or(false);
+
+            if should_broadcast {
+                let confirmations = std::env::var("CONFIRMATIONS")
+                    .ok()
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .unwrap_or(1);
+
+                println!("üì° Broadcasting and awaiting {} confirmation(s)...", confirmations);
+                let envelope: alloy_consensus::TxEnvelope = signed_tx.into();
+                match broadcast::broadcast_and_confirm(&provider, &envelope, expected_address, nonce, confirmations).await {
+                    Ok(broadcast::Resolution::Confirmed(receipt)) => {
+                        println!("‚úÖ Transaction confirmed: {:?}", receipt.transaction_hash);
+                    }
+                    Ok(broadcast::Resolution::Replaced) => {
+                        println!("‚ö†Ô∏è  Transaction was replaced by another using the same nonce");
+                    }
+                    Ok(broadcast::Resolution::TimedOut) => {
+                        // Gave up watching, not confirmed absent - the transaction may
+                        // still be sitting in the mempool and land later. Releasing the
+                        // nonce here would let the scheduler hand it to a different
+                        // transaction, and if the original still confirms, both would
+                        // collide. Leave it reserved, same as `Replaced`, and let the
+                        // operator decide.
+                        println!("‚ö†Ô∏è  Gave up waiting for confirmation; transaction may still be in-flight, consider re-signing with a bumped fee");
+                    }
+                    Err(e) => {
+                        error!("Broadcast failed: {}", e);
+                        scheduler.release(&account_id, nonce).await;
+                    }
+                }
+            } else {
+                println!("ℹ️  Broadcasting skipped (set BROADCAST_TX=1 to enable)");
+                info!("Transaction would be sent to: {}", to_address);
+            }
 
-            println!("‚úÖ Signing test completed successfully");
+            println!("‚úÖ Signing test completed successfully");
         }
         Err(e) => {
             error!("‚ùå Signature failed: {}", e);
             println!("‚ùå Signature Failed: {}", e);
 
+            // The reserved nonce was never used, so free it for reuse
+            // instead of burning a gap in the account's nonce sequence.
+            scheduler.release(&account_id, nonce).await;
+
             // Cleanup in blocking context before returning error
             let _ = signer.shutdown();
             tokio::task::spawn_blocking(move || {
@@ -356,8 +491,40 @@ async fn run_mpc_signing_test(mpc_config: MpcConfig, test_number: u32) -> Result
     Ok(())
 }
 
+/// Runs `refresh-key-share <config> <account_id> <execution_id> <output_path>`:
+/// proactively re-randomizes one account's key share in place (see
+/// `mpc_client::MpcSigner::refresh_account_key_share`) without changing its
+/// address, then exits. `execution_id` must be the same value every other
+/// participant for this account passes, coordinated out of band the same
+/// way DKG provisioning's `execution_id` is.
+async fn run_refresh_key_share(config_path: &str, account_id: &str, execution_id: &str, output_path: &str) -> Result<()> {
+    let mpc_config = load_mpc_config(config_path)?;
+    let signer = MpcSigner::new(mpc_config).context("Failed to initialize MpcSigner")?;
+    signer.initialize().context("Failed to initialize MPC infrastructure")?;
+
+    println!("Refreshing key share for account_id {}...", account_id);
+    let result = signer.refresh_account_key_share(account_id.to_string(), execution_id.to_string(), output_path.to_string());
+
+    let _ = signer.shutdown();
+    tokio::task::spawn_blocking(move || drop(signer)).await.ok();
+
+    result.context("Key share refresh failed")?;
+    println!("Key share for account_id {} refreshed successfully (address unchanged)", account_id);
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("refresh-key-share") {
+        let usage = "usage: refresh-key-share <config> <account_id> <execution_id> <output_path>";
+        let config_path = args.get(2).context(usage)?;
+        let account_id = args.get(3).context(usage)?;
+        let execution_id = args.get(4).context(usage)?;
+        let output_path = args.get(5).context(usage)?;
+        return run_refresh_key_share(config_path, account_id, execution_id, output_path).await;
+    }
+
     println!("üöÄ MPC Wallet Client - Repeated Initialization Test");
     println!("====================================================");
     println!("This test verifies that MpcSigner can be initialized,");
@@ -365,14 +532,17 @@ async fn main() -> Result<()> {
     println!();
 
     // Get config file path, default to config/client.yaml
-    let config_path = std::env::args()
-        .nth(1)
-        .unwrap_or_else(|| "config/client.yaml".to_string());
+    let config_path = args.get(1).cloned().unwrap_or_else(|| "config/client.yaml".to_string());
 
     println!("üìã Loading configuration from: {}", config_path);
 
     // Load configuration from YAML and create MpcConfig
-    let mpc_config = load_mpc_config(&config_path)?;
+    let mut mpc_config = load_mpc_config(&config_path)?;
+
+    // Overlay environment-variable overrides on top of the YAML values and
+    // validate cross-field invariants (see `config::apply_overrides`).
+    let mut tx_params = TxParams::default();
+    config::apply_overrides(&mut mpc_config, &mut tx_params)?;
 
     // Print available account_ids
     println!("\nüìã Available Account IDs:");
@@ -388,12 +558,16 @@ async fn main() -> Result<()> {
 
     println!("\nüîÑ Running {} test cycles...\n", num_runs);
 
+    // Shared across every test cycle so repeated runs reserve successive
+    // nonces instead of each faking `nonce = test_number`.
+    let scheduler = AccountScheduler::new();
+
     // Run multiple test cycles
     for i in 1..=num_runs {
         // Clone config for each test run
         let config_clone = mpc_config.clone();
 
-        match run_mpc_signing_test(config_clone, i).await {
+        match run_mpc_signing_test(config_clone, i, &scheduler, &tx_params).await {
             Ok(_) => {
                 info!("Test run #{} succeeded", i);
             }
@@ -0,0 +1,81 @@
+//! Per-account nonce scheduling, so concurrent or retried signings for the
+//! same account never reuse or skip a nonce.
+//!
+//! Mirrors the account-based scheduler approach from the Serai Ethereum
+//! integration: each account's nonce counter is seeded once from the chain's
+//! pending transaction count, then every [`AccountScheduler::reserve`] hands
+//! out the next value from an in-memory counter rather than re-querying the
+//! provider. A nonce that never made it into a confirmed transaction (e.g.
+//! the broadcast failed) is returned via [`AccountScheduler::release`] so the
+//! next reservation fills the gap instead of burning it.
+
+use std::collections::{BTreeSet, HashMap};
+
+use alloy::primitives::Address;
+use alloy::providers::Provider;
+use anyhow::{Context, Result};
+use tokio::sync::Mutex;
+
+#[derive(Default)]
+struct AccountState {
+    next_nonce: u64,
+    free_list: BTreeSet<u64>,
+}
+
+/// Hands out monotonically increasing, non-conflicting nonces per
+/// `account_id`. Safe to share across concurrent signings via `&self`.
+pub struct AccountScheduler {
+    accounts: Mutex<HashMap<String, AccountState>>,
+}
+
+impl AccountScheduler {
+    pub fn new() -> Self {
+        Self {
+            accounts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reserves the next nonce to sign with for `account_id`, seeding its
+    /// counter from `provider`'s pending transaction count for `address` the
+    /// first time this account is seen. A nonce freed by a prior
+    /// [`Self::release`] call is handed out before the counter is advanced
+    /// any further.
+    pub async fn reserve(&self, provider: &impl Provider, account_id: &str, address: Address) -> Result<u64> {
+        let mut accounts = self.accounts.lock().await;
+
+        if !accounts.contains_key(account_id) {
+            let pending = provider
+                .get_transaction_count(address)
+                .pending()
+                .await
+                .with_context(|| format!("failed to fetch pending transaction count for {}", address))?;
+            accounts.insert(
+                account_id.to_string(),
+                AccountState {
+                    next_nonce: pending,
+                    free_list: BTreeSet::new(),
+                },
+            );
+        }
+
+        let state = accounts.get_mut(account_id).expect("just inserted above");
+        if let Some(&nonce) = state.free_list.iter().next() {
+            state.free_list.remove(&nonce);
+            return Ok(nonce);
+        }
+
+        let nonce = state.next_nonce;
+        state.next_nonce += 1;
+        Ok(nonce)
+    }
+
+    /// Returns `nonce` to `account_id`'s free-list, e.g. after a broadcast
+    /// fails, so the next [`Self::reserve`] call reuses it instead of
+    /// leaving a permanent gap.
+    pub async fn release(&self, account_id: &str, nonce: u64) {
+        let mut accounts = self.accounts.lock().await;
+        if let Some(state) = accounts.get_mut(account_id) {
+            state.free_list.insert(nonce);
+        }
+    }
+}
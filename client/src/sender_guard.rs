@@ -0,0 +1,41 @@
+//! EIP-3607 sender guard: refuses to sign from an address that has deployed
+//! contract code, since the pubkey-to-address mapping a key share embeds is
+//! never otherwise checked against chain state.
+
+use alloy::primitives::Address;
+use alloy::providers::Provider;
+use thiserror::Error;
+
+/// Dedicated error for [`assert_eoa`], kept distinct from `anyhow::Error` so
+/// callers can match on it instead of string-matching a message.
+#[derive(Debug, Error)]
+pub enum SenderGuardError {
+    #[error(
+        "refusing to sign: account {address} has deployed contract code ({code_len} bytes) - \
+         EIP-3607 forbids originating transactions from a contract address"
+    )]
+    AccountHasCode { address: Address, code_len: usize },
+    #[error("failed to query code for account {address}: {source}")]
+    ProviderError {
+        address: Address,
+        #[source]
+        source: anyhow::Error,
+    },
+}
+
+/// Rejects `address` if it has deployed bytecode, per EIP-3607 ("transactions
+/// must not originate from accounts that have code"). Call this before
+/// `signer.sign_data` so an operator can't accidentally sign from a key
+/// share whose derived address collides with a smart contract.
+pub async fn assert_eoa(provider: &impl Provider, address: Address) -> Result<(), SenderGuardError> {
+    let code = provider
+        .get_code_at(address)
+        .await
+        .map_err(|e| SenderGuardError::ProviderError { address, source: anyhow::anyhow!(e) })?;
+
+    if !code.is_empty() {
+        return Err(SenderGuardError::AccountHasCode { address, code_len: code.len() });
+    }
+
+    Ok(())
+}
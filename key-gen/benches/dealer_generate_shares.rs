@@ -18,6 +18,7 @@ fn build_config(rng: &mut impl RngCore) -> KeyGenConfig {
         child_key: random_child_key(rng),
         output_prefix: "bench_output".to_owned(),
         pubkeys: None,
+        identities: None,
     }
 }
 
@@ -10,7 +10,7 @@ use anyhow::{Result, Context, anyhow};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
-use std::io::Write;
+use std::io::{Read, Write};
 use age::Encryptor;
 
 /// Configuration for key share generation
@@ -28,6 +28,11 @@ pub struct KeyGenConfig {
     pub output_prefix: String,
     /// Age public keys for encrypting each file (optional)
     pub pubkeys: Option<Vec<String>>,
+    /// Age identities (secret keys, or paths to identity files) for
+    /// decrypting an existing encrypted file before appending a new
+    /// account to it. Parallel to `pubkeys`: entry `i` must be the
+    /// identity matching `pubkeys[i]`.
+    pub identities: Option<Vec<String>>,
 }
 
 /// Key share dealer for MPC HD Wallet
@@ -143,16 +148,24 @@ impl KeyShareDealer {
             // Load existing data if file exists
             let mut all_accounts: HashMap<String, serde_json::Value> = if Path::new(existing_filename).exists() {
                 println!("   • Loading existing file: {}", existing_filename);
-                let content = if existing_encrypted {
-                    // Decrypt existing file
-                    return Err(anyhow!("Cannot append to encrypted file. Decryption for appending is not yet supported. Please decrypt manually first."));
+                if existing_encrypted {
+                    let identities = self.config.identities.as_ref().ok_or_else(|| {
+                        anyhow!(
+                            "{} is encrypted but no --identities were provided to decrypt it for appending",
+                            existing_filename
+                        )
+                    })?;
+                    let pubkeys = self.config.pubkeys.as_ref().ok_or_else(|| {
+                        anyhow!("encrypted file present but no --pubkeys configured to validate the identity against")
+                    })?;
+                    self.read_encrypted_file(existing_filename, &identities[i], &pubkeys[i])?
                 } else {
-                    fs::read_to_string(existing_filename)
-                        .with_context(|| format!("Failed to read existing file: {}", existing_filename))?
-                };
+                    let content = fs::read_to_string(existing_filename)
+                        .with_context(|| format!("Failed to read existing file: {}", existing_filename))?;
 
-                serde_json::from_str(&content)
-                    .with_context(|| format!("Failed to parse existing file: {}", existing_filename))?
+                    serde_json::from_str(&content)
+                        .with_context(|| format!("Failed to parse existing file: {}", existing_filename))?
+                }
             } else {
                 HashMap::new()
             };
@@ -224,6 +237,74 @@ impl KeyShareDealer {
         Ok(())
     }
 
+    /// Decrypts an existing age-encrypted file with `identity_str` (a raw
+    /// age identity or a path to an identity file) and returns its parsed
+    /// account map, ready to merge a new account into. Validates that the
+    /// identity actually corresponds to `expected_pubkey` (the recipient the
+    /// file was originally encrypted for) before touching its contents, so a
+    /// mismatched identity fails cleanly instead of producing garbage from a
+    /// wrong-key decrypt attempt.
+    fn read_encrypted_file(
+        &self,
+        input_path: &str,
+        identity_str: &str,
+        expected_pubkey: &str,
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let identity = Self::load_identity(identity_str)?;
+
+        let expected_recipient = expected_pubkey
+            .parse::<age::x25519::Recipient>()
+            .map_err(|e| anyhow!("Invalid age public key '{}': {}", expected_pubkey, e))?;
+        if identity.to_public().to_string() != expected_recipient.to_string() {
+            return Err(anyhow!(
+                "age identity does not match the configured recipient for '{}'; refusing to decrypt",
+                input_path
+            ));
+        }
+
+        let input_file = fs::File::open(input_path)
+            .with_context(|| format!("Failed to open encrypted file: {}", input_path))?;
+
+        let decryptor = age::Decryptor::new(input_file)
+            .with_context(|| format!("Failed to read age header from {}", input_path))?;
+
+        let mut reader = decryptor
+            .decrypt(std::iter::once(&identity as &dyn age::Identity))
+            .with_context(|| format!("Failed to decrypt {} (wrong identity?)", input_path))?;
+
+        let mut decrypted = String::new();
+        reader
+            .read_to_string(&mut decrypted)
+            .with_context(|| format!("Failed to read decrypted contents of {}", input_path))?;
+
+        serde_json::from_str(&decrypted)
+            .with_context(|| format!("Decrypted contents of {} are not valid JSON", input_path))
+    }
+
+    /// Parses `identity_str` as a raw age identity, falling back to reading
+    /// it as a path to an identity file (taking its first non-comment,
+    /// non-empty line) if that fails.
+    fn load_identity(identity_str: &str) -> Result<age::x25519::Identity> {
+        if let Ok(identity) = identity_str.parse::<age::x25519::Identity>() {
+            return Ok(identity);
+        }
+
+        let content = fs::read_to_string(identity_str).with_context(|| {
+            format!(
+                "'{}' is neither a valid age identity nor a readable identity file path",
+                identity_str
+            )
+        })?;
+
+        content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .ok_or_else(|| anyhow!("identity file '{}' contains no identity", identity_str))?
+            .parse::<age::x25519::Identity>()
+            .with_context(|| format!("invalid age identity in file '{}'", identity_str))
+    }
+
     /// Check if encryption is enabled
     pub fn is_encrypted(&self) -> bool {
         self.config.pubkeys.is_some()
@@ -239,6 +320,15 @@ impl KeyShareDealer {
         &self.config.output_prefix
     }
 
+    /// Generated key shares, in party order, without going through
+    /// `save_to_files`. Used by callers (e.g. benchmarks) that want to wire
+    /// the shares straight into a `ParticipantServer` without a disk round-trip.
+    pub fn key_shares(&self) -> Result<&[KeyShare<Secp256k1, SecurityLevel128>]> {
+        self.key_shares
+            .as_deref()
+            .ok_or_else(|| anyhow!("Key shares not generated yet. Call generate_shares() first"))
+    }
+
     /// Create a valid scalar from bytes
     fn create_scalar_from_bytes(&self, bytes: &[u8; 32]) -> Result<NonZero<SecretScalar<Secp256k1>>> {
         let scalar = SecretScalar::<Secp256k1>::from_be_bytes(bytes)
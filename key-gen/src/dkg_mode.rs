@@ -0,0 +1,110 @@
+//! Interactive distributed key generation mode for the key-gen CLI.
+//!
+//! Unlike [`crate::dealer::KeyShareDealer`], which reconstructs the full
+//! secret key on this one machine before splitting it, this mode runs each
+//! party's side of `participant::run_keygen` over the SSE relay so no party
+//! ever holds more than its own share. Intended as the default, trustless
+//! provisioning path; `KeyShareDealer` remains available as an opt-in
+//! testing/bootstrap mode for single-machine setups.
+
+use alloy::primitives::Address;
+use alloy::signers::k256::ecdsa::SigningKey;
+use anyhow::{Context, Result};
+use participant::Client;
+
+/// One party's side of a distributed key generation round for `account_id`.
+/// Every party must be given the same `execution_id` and `n_parties`, and
+/// each must be reachable through the same SSE relay at `sse_url`.
+pub struct DkgParticipant {
+    client: Client,
+    account_id: String,
+    execution_id: Vec<u8>,
+    party_index: u16,
+    n_parties: u16,
+    output_path: String,
+    /// The other parties' addresses, for registering this account's DKG
+    /// rooms before joining them (see [`participant::run_keygen`]). Empty
+    /// when `identity_key_hex` wasn't given, keeping the rooms unrestricted.
+    members: Vec<Address>,
+}
+
+impl DkgParticipant {
+    /// `identity_key_hex`/`member_addresses_hex`, when both given, sign this
+    /// party's DKG rooms closed to exactly `member_addresses_hex` before
+    /// anyone joins them; omit either to keep the legacy unrestricted-room
+    /// behavior.
+    pub fn new(
+        sse_url: &str,
+        account_id: String,
+        execution_id_hex: &str,
+        party_index: u16,
+        n_parties: u16,
+        output_path: String,
+        identity_key_hex: Option<&str>,
+        member_addresses_hex: Option<&str>,
+    ) -> Result<Self> {
+        let url = sse_url.parse().context("invalid SSE relay URL")?;
+        let mut client = Client::new(url).context("failed to create relay client")?;
+        let execution_id = hex::decode(execution_id_hex).context(
+            "execution-id must be hex-encoded and identical across all parties",
+        )?;
+
+        let members = match (identity_key_hex, member_addresses_hex) {
+            (Some(identity_key_hex), Some(member_addresses_hex)) => {
+                let identity_bytes = hex::decode(identity_key_hex.trim_start_matches("0x"))
+                    .context("--identity-key must be hex-encoded")?;
+                let identity = SigningKey::from_slice(&identity_bytes)
+                    .context("--identity-key must be a valid secp256k1 private key")?;
+                client = client.with_identity(identity);
+
+                member_addresses_hex
+                    .split(',')
+                    .map(|s| s.trim().parse::<Address>().context("--member-addresses must be comma-separated 0x-prefixed addresses"))
+                    .collect::<Result<Vec<_>>>()?
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(Self {
+            client,
+            account_id,
+            execution_id,
+            party_index,
+            n_parties,
+            output_path,
+            members,
+        })
+    }
+
+    /// Runs the key-generation and auxiliary-info rounds for this party and
+    /// writes its resulting share to `output_path`, printing the jointly
+    /// computed public key so the operator can confirm by eye that every
+    /// party landed on the same one (there is no single process here that
+    /// can check it automatically, the way `KeyShareDealer::verify_public_key`
+    /// does for the trusted-dealer path).
+    pub async fn run(&self) -> Result<()> {
+        println!(
+            "\n🔐 Running distributed key generation for account '{}' (party {} of {})...",
+            self.account_id, self.party_index, self.n_parties
+        );
+
+        let key_share = participant::run_keygen(
+            &self.client,
+            &self.account_id,
+            &self.execution_id,
+            self.party_index,
+            self.n_parties,
+            &self.output_path,
+            &self.members,
+        )
+        .await
+        .context("distributed key generation failed")?;
+
+        let shared_pubkey_hex = hex::encode(key_share.core.shared_public_key.to_bytes(true));
+        println!("   ✓ Share written to {}", self.output_path);
+        println!("   🔍 Jointly computed public key: {}", shared_pubkey_hex);
+        println!("   💡 Confirm every party printed the same public key above before trusting this share.");
+
+        Ok(())
+    }
+}
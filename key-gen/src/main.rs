@@ -1,7 +1,12 @@
 mod dealer;
+mod dkg_mode;
+mod signing_session;
 
 use dealer::{KeyShareDealer, KeyGenConfig, parse_child_key_hex};
-use anyhow::{Result};
+use dkg_mode::DkgParticipant;
+use signing_session::SigningSession;
+use mpc_client::EthTransactionRequest;
+use anyhow::{Result, bail, Context};
 use clap::Parser;
 use chrono::Local;
 
@@ -18,10 +23,18 @@ fn timestamp() -> String {
 #[command(name = "key-gen")]
 #[command(about = "Generate MPC key shares for HD wallet child accounts", long_about = None)]
 struct Args {
+    /// Provisioning mode: "trusted-dealer" (one machine reconstructs the
+    /// full key and splits it, opt-in for testing/bootstrap only) or "dkg"
+    /// (this process runs only its own party's side of an interactive,
+    /// trustless key generation over the SSE relay; run once per party).
+    #[arg(short = 'm', long, default_value = "trusted-dealer")]
+    mode: String,
+
     /// Child key in hex format (64 characters, 32 bytes)
     /// This should be a pre-derived key from your HD wallet
+    /// (trusted-dealer mode only)
     #[arg(short = 'k', long)]
-    child_key: String,
+    child_key: Option<String>,
 
     /// Account ID for this key (e.g., "m/44/60/0/0/0" or "account_1")
     /// Used as the identifier in the key share files
@@ -32,12 +45,12 @@ struct Args {
     #[arg(short = 'n', long, default_value = "2")]
     n_parties: u16,
 
-    /// Threshold for signing (default: 2)
+    /// Threshold for signing (default: 2, trusted-dealer mode only)
     #[arg(short = 't', long, default_value = "2")]
     threshold: u16,
 
     /// Output file prefix (default: "key_shares")
-    /// Will generate {prefix}_1.json, {prefix}_2.json
+    /// Will generate {prefix}_1.json, {prefix}_2.json (trusted-dealer mode only)
     #[arg(short, long, default_value = "key_shares")]
     output: String,
 
@@ -45,17 +58,231 @@ struct Args {
     /// Format: "pubkey1,pubkey2,pubkey3" where pubkey1 encrypts {prefix}_1.json, etc.
     /// If not provided, files will not be encrypted.
     /// Example: age1ql3z7hjy54pw3hyww5ayyfg7zqgvc7w3j2elw8zmrj2kg5sfn9aqmcac8p,age1...
+    /// (trusted-dealer mode only)
     #[arg(short = 'p', long)]
     pubkeys: Option<String>,
+
+    /// Age identities (secret keys, or paths to identity files) for
+    /// decrypting an existing encrypted output file before appending a new
+    /// account to it (comma-separated, parallel to --pubkeys: identity `i`
+    /// must match pubkey `i`). Only needed when re-running against an
+    /// already-encrypted {prefix}_N.json.age (trusted-dealer mode only).
+    #[arg(long)]
+    identities: Option<String>,
+
+    /// SSE relay URL this party connects through (dkg mode only)
+    #[arg(long)]
+    sse_url: Option<String>,
+
+    /// This party's index, 1-based (dkg mode only)
+    #[arg(long)]
+    party_index: Option<u16>,
+
+    /// Hex-encoded execution id shared by every party in this DKG run
+    /// (sign and dkg modes only); generate one out-of-band and pass the
+    /// same value to every party/signer.
+    #[arg(long)]
+    execution_id: Option<String>,
+
+    /// This party's identity key (hex-encoded secp256k1 private key), used
+    /// to sign DKG/refresh/signing room registration (sign and dkg modes
+    /// only). Omit to keep the legacy unrestricted-room behavior; only
+    /// takes effect together with --member-addresses.
+    #[arg(long)]
+    identity_key: Option<String>,
+
+    /// Every party's address taking part in this run, comma-separated
+    /// 0x-prefixed hex (sign and dkg modes only), used to lock this
+    /// account's rooms to exactly this set before anyone joins. Only takes
+    /// effect together with --identity-key.
+    #[arg(long)]
+    member_addresses: Option<String>,
+
+    /// Path to the key share file this party signs with (sign mode only)
+    #[arg(long)]
+    key_share_file: Option<String>,
+
+    /// Comma-separated party indexes taking part in this signing round
+    /// (sign mode only)
+    #[arg(long)]
+    participants: Option<String>,
+
+    /// Hex-encoded 32-byte message hash/digest to sign (sign mode only)
+    #[arg(long)]
+    message_hash: Option<String>,
+
+    /// Comma-separated BIP32 derivation path indexes to additively tweak
+    /// the root share with before signing (sign mode only, omit to sign
+    /// with the root share directly)
+    #[arg(long)]
+    derivation_path: Option<String>,
+
+    /// If set, assemble the signature into a signed legacy Ethereum
+    /// transaction for this chain id instead of printing the raw signature
+    /// (sign mode only)
+    #[arg(long)]
+    eth_chain_id: Option<u64>,
+
+    /// Transaction nonce (required with --eth-chain-id)
+    #[arg(long)]
+    eth_nonce: Option<u64>,
+
+    /// Gas price in wei (required with --eth-chain-id)
+    #[arg(long)]
+    eth_gas_price: Option<u128>,
+
+    /// Gas limit (required with --eth-chain-id)
+    #[arg(long)]
+    eth_gas_limit: Option<u64>,
+
+    /// Recipient address, 40 hex characters, omit for contract creation
+    /// (--eth-chain-id only)
+    #[arg(long)]
+    eth_to: Option<String>,
+
+    /// Value to transfer, in wei (--eth-chain-id only, default 0)
+    #[arg(long, default_value = "0")]
+    eth_value: u128,
+
+    /// Calldata, hex-encoded (--eth-chain-id only, default empty)
+    #[arg(long, default_value = "")]
+    eth_data: String,
 }
 
-fn main() -> Result<()> {
+#[tokio::main]
+async fn main() -> Result<()> {
     let args = Args::parse();
 
     println!("{} === MPC HD Wallet Key Share Generator ===\n", timestamp());
 
+    match args.mode.as_str() {
+        "dkg" => run_dkg_mode(&args).await,
+        "trusted-dealer" => run_trusted_dealer_mode(&args),
+        "sign" => run_sign_mode(&args).await,
+        other => bail!("unknown --mode '{}', expected 'trusted-dealer', 'dkg' or 'sign'", other),
+    }
+}
+
+/// Loads this party's share and drives threshold signing over the SSE
+/// relay, optionally assembling the result into a signed Ethereum
+/// transaction when `--eth-chain-id` is given.
+async fn run_sign_mode(args: &Args) -> Result<()> {
+    let sse_url = args.sse_url.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--sse-url is required in sign mode"))?;
+    let key_share_file = args.key_share_file.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--key-share-file is required in sign mode"))?;
+    let execution_id_hex = args.execution_id.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--execution-id is required in sign mode"))?;
+    let message_hash_hex = args.message_hash.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--message-hash is required in sign mode"))?;
+    let participants_str = args.participants.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--participants is required in sign mode"))?;
+
+    let execution_id = hex::decode(execution_id_hex).context("--execution-id must be hex")?;
+    let message_hash_bytes = hex::decode(message_hash_hex).context("--message-hash must be hex")?;
+    let message_hash: [u8; 32] = message_hash_bytes.try_into()
+        .map_err(|_| anyhow::anyhow!("--message-hash must be exactly 32 bytes"))?;
+
+    let participants: Vec<u16> = participants_str
+        .split(',')
+        .map(|s| s.trim().parse::<u16>().context("--participants must be comma-separated integers"))
+        .collect::<Result<_>>()?;
+
+    let derivation_path: Vec<u32> = match args.derivation_path.as_deref() {
+        Some(path_str) if !path_str.is_empty() => path_str
+            .split(',')
+            .map(|s| s.trim().parse::<u32>().context("--derivation-path must be comma-separated integers"))
+            .collect::<Result<_>>()?,
+        _ => Vec::new(),
+    };
+
+    let eth_tx = match args.eth_chain_id {
+        Some(chain_id) => {
+            let nonce = args.eth_nonce.ok_or_else(|| anyhow::anyhow!("--eth-nonce is required with --eth-chain-id"))?;
+            let gas_price = args.eth_gas_price.ok_or_else(|| anyhow::anyhow!("--eth-gas-price is required with --eth-chain-id"))?;
+            let gas_limit = args.eth_gas_limit.ok_or_else(|| anyhow::anyhow!("--eth-gas-limit is required with --eth-chain-id"))?;
+            let to = match &args.eth_to {
+                Some(to_hex) => {
+                    let bytes = hex::decode(to_hex.trim_start_matches("0x")).context("--eth-to must be hex")?;
+                    let addr: [u8; 20] = bytes.try_into()
+                        .map_err(|_| anyhow::anyhow!("--eth-to must be exactly 20 bytes"))?;
+                    Some(addr)
+                }
+                None => None,
+            };
+            let data = hex::decode(args.eth_data.trim_start_matches("0x")).context("--eth-data must be hex")?;
+
+            Some(EthTransactionRequest::Legacy {
+                chain_id,
+                nonce,
+                gas_price,
+                gas_limit,
+                to,
+                value: args.eth_value,
+                data,
+            })
+        }
+        None => None,
+    };
+
+    println!("{} ✍️  Loading key share from {}...", timestamp(), key_share_file);
+    let session = SigningSession::load(
+        sse_url,
+        key_share_file,
+        &args.account_id,
+        participants,
+        args.identity_key.as_deref(),
+        args.member_addresses.as_deref(),
+    )?;
+
+    println!("{} 🤝 Running threshold signing protocol...", timestamp());
+    let outcome = session.sign(&execution_id, message_hash, &derivation_path, eth_tx).await?;
+
+    println!("\n{} ✅ Signature produced!", timestamp());
+    println!("   r: 0x{}", hex::encode(outcome.r));
+    println!("   s: 0x{}", hex::encode(outcome.s));
+    println!("   v: {}", outcome.v);
+    if let Some(signed_tx) = &outcome.signed_tx {
+        println!("   Signed tx (RLP): 0x{}", hex::encode(signed_tx));
+    }
+
+    Ok(())
+}
+
+/// Runs this party's side of an interactive distributed key generation,
+/// never reconstructing the full secret key on this machine.
+async fn run_dkg_mode(args: &Args) -> Result<()> {
+    let sse_url = args.sse_url.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--sse-url is required in dkg mode"))?;
+    let party_index = args.party_index
+        .ok_or_else(|| anyhow::anyhow!("--party-index is required in dkg mode"))?;
+    let execution_id = args.execution_id.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--execution-id is required in dkg mode"))?;
+
+    let participant = DkgParticipant::new(
+        sse_url,
+        args.account_id.clone(),
+        execution_id,
+        party_index,
+        args.n_parties,
+        format!("{}_{}.json", args.output, party_index),
+        args.identity_key.as_deref(),
+        args.member_addresses.as_deref(),
+    )?;
+
+    participant.run().await?;
+
+    println!("\n{} ✅ Distributed key generation complete for this party!", timestamp());
+    Ok(())
+}
+
+/// Runs the existing single-machine trusted-dealer provisioning path.
+fn run_trusted_dealer_mode(args: &Args) -> Result<()> {
+    let child_key_hex = args.child_key.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("--child-key is required in trusted-dealer mode"))?;
+
     // Parse child key
-    let child_key = parse_child_key_hex(&args.child_key)?;
+    let child_key = parse_child_key_hex(child_key_hex)?;
 
     println!("{} 🔑 Account ID: {}", timestamp(), args.account_id);
     println!("{}    Child Key (hex): {}", timestamp(), hex::encode(&child_key));
@@ -82,14 +309,36 @@ fn main() -> Result<()> {
         None
     };
 
+    // Parse identities if provided (only needed when appending to an
+    // already-encrypted file)
+    let identities = if let Some(ref identities_str) = args.identities {
+        let ids: Vec<String> = identities_str
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        if ids.len() != args.n_parties as usize {
+            eprintln!("{} ⚠️  Warning: Number of identities ({}) doesn't match number of parties ({})",
+                     timestamp(), ids.len(), args.n_parties);
+            eprintln!("{}    Appending to encrypted files will fail if needed.", timestamp());
+            None
+        } else {
+            Some(ids)
+        }
+    } else {
+        None
+    };
+
     // Create key generation configuration
     let config = KeyGenConfig {
         n_parties: args.n_parties,
         threshold: args.threshold,
-        account_id: args.account_id,
+        account_id: args.account_id.clone(),
         child_key,
-        output_prefix: args.output,
+        output_prefix: args.output.clone(),
         pubkeys,
+        identities,
     };
 
     // Create dealer and generate key shares
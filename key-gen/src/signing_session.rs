@@ -0,0 +1,204 @@
+//! Threshold-signing coordinator for the key-gen CLI, the counterpart to
+//! [`crate::dealer::KeyShareDealer`]: where the dealer produces shares, a
+//! `SigningSession` loads one of them back and drives cggmp21's signing
+//! protocol over the same SSE relay transport [`crate::dkg_mode::DkgParticipant`]
+//! uses, optionally applying a BIP32 derivation path before signing and
+//! optionally assembling the result into a signed Ethereum transaction.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use alloy::primitives::Address;
+use alloy::signers::k256::ecdsa::{Signature, SigningKey};
+use anyhow::{anyhow, Context, Result};
+use cggmp21::hd_wallet::Slip10;
+use cggmp21::round_based::MpcParty;
+use cggmp21::security_level::SecurityLevel128;
+use cggmp21::signing::msg::Msg;
+use cggmp21::supported_curves::Secp256k1;
+use cggmp21::{DataToSign, ExecutionId, KeyShare};
+use generic_ec::Scalar;
+use mpc_client::EthTransactionRequest;
+use participant::Client;
+use rand::rngs::OsRng;
+use sha2::Sha256;
+
+/// Raw secp256k1 signature plus, if an [`EthTransactionRequest`] was
+/// supplied to [`SigningSession::sign`], the RLP/EIP-2718-encoded signed
+/// transaction ready to broadcast.
+pub struct SignOutcome {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub v: u8,
+    pub signed_tx: Option<Vec<u8>>,
+}
+
+pub struct SigningSession {
+    client: Client,
+    key_share: KeyShare<Secp256k1, SecurityLevel128>,
+    participants: Vec<u16>,
+    /// The other signers' addresses, for registering this signing round's
+    /// room before joining it (see [`Self::sign`]). Empty when
+    /// `identity_key_hex`/`member_addresses_hex` weren't given to
+    /// [`Self::load`], keeping the room unrestricted.
+    members: Vec<Address>,
+}
+
+impl SigningSession {
+    /// Loads this party's share for `account_id` out of a key-share file
+    /// written by [`crate::dealer::KeyShareDealer::save_to_files`] or
+    /// [`crate::dkg_mode::DkgParticipant`], and connects to the SSE relay
+    /// the other `participants` will also join through.
+    ///
+    /// `identity_key_hex`/`member_addresses_hex`, when both given, sign this
+    /// round's signing room closed to exactly `member_addresses_hex` before
+    /// anyone joins it; omit either to keep the legacy unrestricted-room
+    /// behavior.
+    pub fn load(
+        sse_url: &str,
+        key_share_path: impl AsRef<Path>,
+        account_id: &str,
+        participants: Vec<u16>,
+        identity_key_hex: Option<&str>,
+        member_addresses_hex: Option<&str>,
+    ) -> Result<Self> {
+        let key_share_path = key_share_path.as_ref();
+        let content = fs::read_to_string(key_share_path)
+            .with_context(|| format!("failed to read key share file {}", key_share_path.display()))?;
+        let shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>> =
+            serde_json::from_str(&content)
+                .with_context(|| format!("failed to parse key share file {}", key_share_path.display()))?;
+        let key_share = shares
+            .get(account_id)
+            .ok_or_else(|| anyhow!("account '{}' not found in {}", account_id, key_share_path.display()))?
+            .clone();
+
+        let mut client = Client::new(sse_url.parse().context("invalid SSE relay URL")?)
+            .context("failed to create relay client")?;
+
+        let members = match (identity_key_hex, member_addresses_hex) {
+            (Some(identity_key_hex), Some(member_addresses_hex)) => {
+                let identity_bytes = hex::decode(identity_key_hex.trim_start_matches("0x"))
+                    .context("--identity-key must be hex-encoded")?;
+                let identity = SigningKey::from_slice(&identity_bytes)
+                    .context("--identity-key must be a valid secp256k1 private key")?;
+                client = client.with_identity(identity);
+
+                member_addresses_hex
+                    .split(',')
+                    .map(|s| s.trim().parse::<Address>().context("--member-addresses must be comma-separated 0x-prefixed addresses"))
+                    .collect::<Result<Vec<_>>>()?
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(Self {
+            client,
+            key_share,
+            participants,
+            members,
+        })
+    }
+
+    /// Runs the signing protocol for `message_hash` (already hashed;
+    /// digested again by no one), applying `derivation_path` as an additive
+    /// HD tweak on top of the share's root key when non-empty, and
+    /// optionally assembling the result into a signed Ethereum transaction
+    /// when `eth_tx` is given.
+    pub async fn sign(
+        &self,
+        execution_id: &[u8],
+        message_hash: [u8; 32],
+        derivation_path: &[u32],
+        eth_tx: Option<EthTransactionRequest>,
+    ) -> Result<SignOutcome> {
+        let eid = ExecutionId::new(execution_id);
+        let party_index = self.key_share.core.i;
+
+        let room = self.client.room(&format!("signing_{}", hex::encode(execution_id)));
+        if !self.members.is_empty() {
+            room.register_or_join(self.members.clone(), self.participants.len())
+                .await
+                .context("failed to register signing room")?;
+        }
+        let (_, incoming, outgoing) = room.join_room::<Msg<Secp256k1, Sha256>>(party_index).await?;
+        let party = MpcParty::connected((incoming, outgoing));
+
+        let signing = cggmp21::signing(eid, party_index, &self.participants, &self.key_share);
+        let signing = if derivation_path.is_empty() {
+            signing
+        } else {
+            signing
+                .set_derivation_path_with_algo::<Slip10, _>(derivation_path.iter().copied())
+                .context("invalid HD derivation path")?
+        };
+
+        let data = DataToSign::from_scalar(Scalar::from_be_bytes_mod_order(&message_hash));
+        let signature = signing
+            .sign(&mut OsRng, party, data)
+            .await
+            .context("signing protocol failed")?;
+
+        let r_bytes = signature.r.into_inner().to_be_bytes();
+        let s_bytes = signature.s.into_inner().to_be_bytes();
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(r_bytes.as_bytes());
+        s.copy_from_slice(s_bytes.as_bytes());
+
+        // EIP-2 (and BIP-62 for Bitcoin) reject/discourage a signature whose
+        // `s` lies in the upper half of the curve order, so canonicalize it
+        // to the low-S form here, same as `Signing::sign_tx` does for every
+        // chain. `s` and `n - s` both verify against the same public key but
+        // under opposite recovery parity, so the recovery-id search below
+        // (which recomputes against whatever `s` ends up being) naturally
+        // lands on the matching parity - no separate bit-flip needed.
+        if let Ok(sig) = Signature::from_slice(&[r.as_slice(), &s].concat()) {
+            if let Some(normalized) = sig.normalize_s() {
+                s.copy_from_slice(normalized.s().to_bytes().as_slice());
+            }
+        }
+
+        let v = recovery_id(&self.key_share, derivation_path, &message_hash, &r, &s)?;
+        let signed_tx = eth_tx.map(|tx| tx.into_signed_bytes(&r, &s, v));
+
+        Ok(SignOutcome { r, s, v, signed_tx })
+    }
+}
+
+/// Recovers the `ecrecover`-compatible recovery id (0 or 1) by trying both
+/// candidates against the (possibly HD-derived) public key this signature
+/// should verify under.
+fn recovery_id(
+    key_share: &KeyShare<Secp256k1, SecurityLevel128>,
+    derivation_path: &[u32],
+    message_hash: &[u8; 32],
+    r: &[u8; 32],
+    s: &[u8; 32],
+) -> Result<u8> {
+    use alloy::signers::k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    let public_key = if derivation_path.is_empty() {
+        key_share.core.shared_public_key
+    } else {
+        key_share
+            .derive_child_public_key::<Slip10, _>(derivation_path.iter().copied())
+            .context("failed to derive child public key")?
+            .public_key
+    };
+
+    let sig = Signature::from_slice(&[r.as_slice(), s.as_slice()].concat())
+        .context("malformed signature")?;
+
+    for candidate in [0u8, 1u8] {
+        let recovery_id = RecoveryId::try_from(candidate).context("invalid recovery id")?;
+        if let Ok(recovered) = VerifyingKey::recover_from_prehash(message_hash, &sig, recovery_id) {
+            if recovered.to_encoded_point(false).as_bytes() == public_key.to_bytes(false).as_ref() {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    Err(anyhow!("could not determine recovery id for signature"))
+}
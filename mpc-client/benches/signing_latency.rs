@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use cggmp21::security_level::SecurityLevel128;
+use cggmp21::supported_curves::Secp256k1;
+use cggmp21::KeyShare;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use key_gen::dealer::{KeyGenConfig, KeyShareDealer};
+use mpc_client::{KeyShareData, Signer, SignerConfig};
+use participant::ParticipantServer;
+use proto::mpc::Chain;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use sse::{AppConfig as SseAppConfig, SSEConfig, SseServer};
+use tokio::runtime::Runtime;
+use tokio::sync::Mutex;
+
+const SSE_PORT: u16 = 18180;
+const GATEWAY_PARTICIPANT_PORT: u16 = 18181;
+const LOCAL_PARTICIPANT_PORT: u16 = 18182;
+const METRICS_PORT: u16 = 18183;
+
+/// Stands in for an HD wallet derivation path of `depth` hops: this repo's
+/// dealer (`key_gen::dealer::KeyGenConfig`) takes a single pre-derived
+/// 32-byte child key rather than a path, so we fold the seed through SHA-256
+/// `depth` times to get a distinct child key per depth.
+fn derive_child_key(depth: u32) -> [u8; 32] {
+    let mut key = [0x42u8; 32];
+    for _ in 0..=depth {
+        let mut hasher = Sha256::new();
+        hasher.update(key);
+        key = hasher.finalize().into();
+    }
+    key
+}
+
+/// Deals a 2-of-2 key share pair for `account_id` at the given derivation
+/// depth, returning (party 0's share, party 1's share).
+fn deal_shares(
+    account_id: &str,
+    depth: u32,
+) -> (
+    KeyShare<Secp256k1, SecurityLevel128>,
+    KeyShare<Secp256k1, SecurityLevel128>,
+) {
+    let config = KeyGenConfig {
+        n_parties: 2,
+        threshold: 2,
+        account_id: account_id.to_string(),
+        child_key: derive_child_key(depth),
+        output_prefix: format!("bench_{account_id}"),
+        pubkeys: None,
+        identities: None,
+    };
+    let mut dealer = KeyShareDealer::new(config).expect("config is valid");
+    dealer.generate_shares().expect("generation succeeds");
+    let shares = dealer.key_shares().expect("shares were just generated");
+    (shares[0].clone(), shares[1].clone())
+}
+
+/// Boots the loopback SSE relay, the "remote" participant acting as the
+/// sign-gateway's counterpart, and a `Signer` with its own local
+/// participant, all wired to a single account. Exercises the same
+/// `Signer::sign` path a real client drives, just against processes in this
+/// benchmark binary instead of over the network.
+///
+/// Each depth gets its own trio of loopback ports (offset by `port_offset`)
+/// so the per-depth signer/server sets can coexist for the lifetime of the
+/// benchmark instead of fighting over the same bind address.
+async fn setup_signer(account_id: &str, depth: u32, port_offset: u16) -> Signer {
+    let sse_port = SSE_PORT + port_offset;
+    let gateway_port = GATEWAY_PARTICIPANT_PORT + port_offset;
+    let local_port = LOCAL_PARTICIPANT_PORT + port_offset;
+    let metrics_port = METRICS_PORT + port_offset;
+
+    let (local_share, gateway_share) = deal_shares(account_id, depth);
+
+    let sse_config = SseAppConfig {
+        sse: SSEConfig {
+            host: "127.0.0.1".to_string(),
+            port: sse_port,
+            history_capacity: sse::DEFAULT_ROOM_HISTORY_CAPACITY,
+        },
+    };
+    let sse_server = SseServer::new(sse_config);
+    tokio::spawn(async move {
+        let _ = sse_server.start().await;
+    });
+
+    let mut gateway_shares = HashMap::new();
+    gateway_shares.insert(account_id.to_string(), gateway_share);
+    let gateway_server = ParticipantServer::new(
+        &format!("http://127.0.0.1:{sse_port}"),
+        "127.0.0.1",
+        gateway_port,
+        gateway_shares,
+    )
+    .expect("mock sign-gateway participant server starts");
+    let gateway_server_clone = gateway_server.clone();
+    tokio::spawn(async move {
+        let _ = gateway_server_clone.start().await;
+    });
+
+    // Give both loopback HTTP servers a moment to bind before dialing them.
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let signer_config = SignerConfig {
+        local_participant_host: "127.0.0.1".to_string(),
+        local_participant_port: local_port,
+        local_participant_index: local_share.core.i,
+        key_shares: vec![KeyShareData {
+            account_id: account_id.to_string(),
+            key_share_data: serde_json::to_string(&local_share)
+                .expect("key share serializes"),
+        }],
+        sign_gateway_host: "127.0.0.1".to_string(),
+        sign_gateway_port: gateway_port,
+        sse_host: "127.0.0.1".to_string(),
+        sse_port,
+        threshold: 2,
+        total_participants: 2,
+        log_level: "error".to_string(),
+        connectivity_check_interval_secs: mpc_client::DEFAULT_CONNECTIVITY_CHECK_INTERVAL_SECS,
+        metrics_host: "127.0.0.1".to_string(),
+        metrics_port,
+        tls: None,
+        proxy: None,
+    };
+
+    let mut signer = Signer::new(signer_config, tokio::runtime::Handle::current())
+        .await
+        .expect("signer connects to the mock sign-gateway");
+    signer
+        .start_local_participant()
+        .await
+        .expect("local participant joins the room");
+    signer
+}
+
+fn payload_of(size: usize) -> Vec<u8> {
+    let mut data = vec![0u8; size];
+    rand::thread_rng().fill_bytes(&mut data);
+    data
+}
+
+fn benchmark_sign_latency(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("tokio runtime");
+    let mut group = c.benchmark_group("signer_sign_round_trip");
+    group.sample_size(10);
+
+    for (depth_idx, depth) in [0u32, 3].into_iter().enumerate() {
+        let account_id = format!("bench_acct_d{depth}");
+        let signer =
+            runtime.block_on(setup_signer(&account_id, depth, (depth_idx as u16) * 10));
+        let signer = Arc::new(Mutex::new(signer));
+
+        for payload_bytes in [32usize, 256, 4096] {
+            let bench_id = BenchmarkId::new(format!("depth={depth}"), payload_bytes);
+            group.bench_with_input(bench_id, &payload_bytes, |b, &payload_bytes| {
+                b.to_async(&runtime).iter(|| {
+                    let signer = signer.clone();
+                    let account_id = account_id.clone();
+                    let data = payload_of(payload_bytes);
+                    async move {
+                        signer
+                            .lock()
+                            .await
+                            .sign(data, account_id, Chain::Ethereum)
+                            .await
+                            .expect("sign round trip succeeds")
+                    }
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_sign_latency);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+use alloy::signers::k256::ecdsa::{RecoveryId, Signature as K256Signature, VerifyingKey};
+use proto::mpc::Chain;
+
+/// Per-chain conventions for verifying an MPC-produced ECDSA signature
+/// against the account's shared public key. `Signer::sign` looks up the
+/// implementation matching a request's `Chain` via [`chain_signer`] rather
+/// than hardcoding Ethereum's `ecrecover` convention for every chain.
+pub trait ChainSigner {
+    /// Recovers the public key `r`/`s`/`v` would produce over `message_hash`
+    /// under this chain's recovery-id convention, and checks it against
+    /// `expected_public_key`.
+    fn verify(&self, expected_public_key: &VerifyingKey, message_hash: &[u8], r: &[u8], s: &[u8], v: u32) -> bool;
+}
+
+/// ECDSA recovery as used by Ethereum's `ecrecover`: `v` is the raw recovery
+/// id (0 or 1) computed by `participant::signing::Signing::sign_tx`'s
+/// `Chain::Ethereum` branch.
+pub struct EthereumSigner;
+
+impl ChainSigner for EthereumSigner {
+    fn verify(&self, expected_public_key: &VerifyingKey, message_hash: &[u8], r: &[u8], s: &[u8], v: u32) -> bool {
+        let Ok(v) = u8::try_from(v) else { return false };
+        let Ok(recovery_id) = RecoveryId::try_from(v) else { return false };
+        let Ok(sig) = K256Signature::from_slice(&[r, s].concat()) else { return false };
+        match VerifyingKey::recover_from_prehash(message_hash, &sig, recovery_id) {
+            Ok(recovered) => {
+                recovered.to_encoded_point(false).as_bytes() == expected_public_key.to_encoded_point(false).as_bytes()
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// Bitcoin shares Ethereum's secp256k1 curve and, since
+/// `participant::signing::Signing::sign_tx`'s `Chain::Bitcoin` branch now
+/// computes a real recovery id the same way Ethereum's does, the same
+/// recovery-and-compare check applies unchanged. Bitcoin itself has no wire
+/// convention for `v` the way Ethereum's `ecrecover` does, but it's still a
+/// meaningful recovery id here for verification and for
+/// `participant::to_compact_recoverable`'s `header` byte.
+pub struct BitcoinSigner;
+
+impl ChainSigner for BitcoinSigner {
+    fn verify(&self, expected_public_key: &VerifyingKey, message_hash: &[u8], r: &[u8], s: &[u8], v: u32) -> bool {
+        let Ok(v) = u8::try_from(v) else { return false };
+        let Ok(recovery_id) = RecoveryId::try_from(v) else { return false };
+        let Ok(sig) = K256Signature::from_slice(&[r, s].concat()) else { return false };
+        match VerifyingKey::recover_from_prehash(message_hash, &sig, recovery_id) {
+            Ok(recovered) => {
+                recovered.to_encoded_point(false).as_bytes() == expected_public_key.to_encoded_point(false).as_bytes()
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// Returns the [`ChainSigner`] matching a `SignMessage`'s `chain` field.
+pub fn chain_signer(chain: Chain) -> Box<dyn ChainSigner> {
+    match chain {
+        Chain::Ethereum => Box::new(EthereumSigner),
+        Chain::Bitcoin => Box::new(BitcoinSigner),
+    }
+}
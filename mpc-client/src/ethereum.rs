@@ -0,0 +1,321 @@
+use alloy::primitives::{keccak256, Address, Signature as AlloySignature, U256};
+use alloy_consensus::{Signed, TxEnvelope, TypedTransaction};
+use anyhow::Result;
+use proto::mpc::Chain;
+
+use crate::rlp::RlpItem;
+use crate::signer::{SignatureResult, Signer};
+
+/// One entry of an EIP-2930 access list: an address plus the storage slots
+/// within it the transaction will touch.
+#[derive(Debug, Clone)]
+pub struct AccessListItem {
+    pub address: [u8; 20],
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+impl AccessListItem {
+    fn to_item(&self) -> RlpItem {
+        RlpItem::List(vec![
+            RlpItem::bytes(self.address.to_vec()),
+            RlpItem::List(self.storage_keys.iter().map(|key| RlpItem::bytes(key.to_vec())).collect()),
+        ])
+    }
+}
+
+fn access_list_item(access_list: &[AccessListItem]) -> RlpItem {
+    RlpItem::List(access_list.iter().map(AccessListItem::to_item).collect())
+}
+
+/// An unsigned Ethereum transaction to sign, in the minimal shape this
+/// crate's MPC signing path needs rather than a full `alloy` transaction
+/// type.
+#[derive(Debug, Clone)]
+pub enum EthTransactionRequest {
+    Legacy {
+        chain_id: u64,
+        nonce: u64,
+        gas_price: u128,
+        gas_limit: u64,
+        to: Option<[u8; 20]>,
+        value: u128,
+        data: Vec<u8>,
+    },
+    Eip1559 {
+        chain_id: u64,
+        nonce: u64,
+        max_priority_fee_per_gas: u128,
+        max_fee_per_gas: u128,
+        gas_limit: u64,
+        to: Option<[u8; 20]>,
+        value: u128,
+        data: Vec<u8>,
+        access_list: Vec<AccessListItem>,
+    },
+}
+
+impl EthTransactionRequest {
+    fn to_item(to: &Option<[u8; 20]>) -> RlpItem {
+        match to {
+            Some(addr) => RlpItem::bytes(addr.to_vec()),
+            None => RlpItem::bytes(Vec::new()),
+        }
+    }
+
+    /// RLP payload whose keccak256 is the digest the MPC signers actually
+    /// sign: EIP-155 encoding (`[..., chain_id, 0, 0]`) for legacy, the
+    /// EIP-2718 typed payload (`0x02 || rlp([...])`) for EIP-1559.
+    fn signing_preimage(&self) -> Vec<u8> {
+        match self {
+            Self::Legacy {
+                chain_id,
+                nonce,
+                gas_price,
+                gas_limit,
+                to,
+                value,
+                data,
+            } => RlpItem::List(vec![
+                RlpItem::uint(*nonce as u128),
+                RlpItem::uint(*gas_price),
+                RlpItem::uint(*gas_limit as u128),
+                Self::to_item(to),
+                RlpItem::uint(*value),
+                RlpItem::bytes(data.clone()),
+                RlpItem::uint(*chain_id as u128),
+                RlpItem::uint(0),
+                RlpItem::uint(0),
+            ])
+            .encode(),
+            Self::Eip1559 {
+                chain_id,
+                nonce,
+                max_priority_fee_per_gas,
+                max_fee_per_gas,
+                gas_limit,
+                to,
+                value,
+                data,
+                access_list,
+            } => {
+                let mut out = vec![0x02u8];
+                out.extend(
+                    RlpItem::List(vec![
+                        RlpItem::uint(*chain_id as u128),
+                        RlpItem::uint(*nonce as u128),
+                        RlpItem::uint(*max_priority_fee_per_gas),
+                        RlpItem::uint(*max_fee_per_gas),
+                        RlpItem::uint(*gas_limit as u128),
+                        Self::to_item(to),
+                        RlpItem::uint(*value),
+                        RlpItem::bytes(data.clone()),
+                        access_list_item(access_list),
+                    ])
+                    .encode(),
+                );
+                out
+            }
+        }
+    }
+
+    /// Assembles the broadcast-ready signed transaction from a secp256k1
+    /// signature obtained however the caller likes (not just
+    /// `Signer::sign_ethereum_tx`), normalizing `v`: for legacy transactions,
+    /// EIP-155 (`recovery_id + chain_id*2 + 35`) when `chain_id` is nonzero,
+    /// or the pre-EIP-155 `recovery_id + 27` when it's `0`; for the EIP-2718
+    /// envelope, the raw `y_parity` (0/1) untouched.
+    pub fn into_signed_bytes(self, r: &[u8], s: &[u8], recovery_id: u8) -> Vec<u8> {
+        match self {
+            Self::Legacy {
+                chain_id,
+                nonce,
+                gas_price,
+                gas_limit,
+                to,
+                value,
+                data,
+            } => {
+                let v = if chain_id == 0 {
+                    u128::from(recovery_id) + 27
+                } else {
+                    u128::from(recovery_id) + u128::from(chain_id) * 2 + 35
+                };
+                RlpItem::List(vec![
+                    RlpItem::uint(nonce as u128),
+                    RlpItem::uint(gas_price),
+                    RlpItem::uint(gas_limit as u128),
+                    Self::to_item(&to),
+                    RlpItem::uint(value),
+                    RlpItem::bytes(data),
+                    RlpItem::uint(v),
+                    RlpItem::bytes(r.to_vec()),
+                    RlpItem::bytes(s.to_vec()),
+                ])
+                .encode()
+            }
+            Self::Eip1559 {
+                chain_id,
+                nonce,
+                max_priority_fee_per_gas,
+                max_fee_per_gas,
+                gas_limit,
+                to,
+                value,
+                data,
+                access_list,
+            } => {
+                let mut out = vec![0x02u8];
+                out.extend(
+                    RlpItem::List(vec![
+                        RlpItem::uint(chain_id as u128),
+                        RlpItem::uint(nonce as u128),
+                        RlpItem::uint(max_priority_fee_per_gas),
+                        RlpItem::uint(max_fee_per_gas),
+                        RlpItem::uint(gas_limit as u128),
+                        Self::to_item(&to),
+                        RlpItem::uint(value),
+                        RlpItem::bytes(data),
+                        access_list_item(&access_list),
+                        RlpItem::uint(recovery_id as u128),
+                        RlpItem::bytes(r.to_vec()),
+                        RlpItem::bytes(s.to_vec()),
+                    ])
+                    .encode(),
+                );
+                out
+            }
+        }
+    }
+}
+
+/// Result of [`Signer::sign_ethereum_tx`]: the broadcast-ready signed
+/// transaction, its hash, and the address it recovers to, so callers can
+/// assert it matches the account they expected to sign with and submit
+/// `raw` to a node directly.
+#[derive(Debug, Clone)]
+pub struct SignedEthTransaction {
+    pub raw: Vec<u8>,
+    pub tx_hash: [u8; 32],
+    pub from: Address,
+    pub signature: SignatureResult,
+}
+
+/// Input to [`Signer::sign_payload`]: the transaction-signing path above
+/// already knows how to hash itself (see `EthTransactionRequest`), but
+/// off-chain signature flows - wallet login, typed-data approvals - need
+/// their own domain hashing applied before the MPC round runs.
+#[derive(Debug, Clone)]
+pub enum SignPayload {
+    /// Already the 32-byte digest to sign, e.g. one a caller hashed some
+    /// other way. Equivalent to the pre-`SignPayload` `sign_ethereum_digest`.
+    PrehashedDigest([u8; 32]),
+    /// A raw message to sign under Ethereum's `personal_sign` / EIP-191
+    /// convention: `keccak256("\x19Ethereum Signed Message:\n" ++
+    /// decimal(len(message)) ++ message)`.
+    PersonalMessage(Vec<u8>),
+    /// An EIP-712 typed-data digest, already computed as
+    /// `keccak256(0x1901 ‖ domainSeparator ‖ hashStruct(message))` - EIP-712
+    /// mixes in a 2-byte/32-byte/32-byte preimage the crate has no reason to
+    /// parse, so the caller hashes it and hands over the result.
+    Eip712Digest([u8; 32]),
+}
+
+impl SignPayload {
+    /// The 32-byte digest the MPC signers actually sign over.
+    fn digest(&self) -> [u8; 32] {
+        match self {
+            Self::PrehashedDigest(digest) | Self::Eip712Digest(digest) => *digest,
+            Self::PersonalMessage(message) => {
+                let mut preimage = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+                preimage.extend_from_slice(message);
+                *keccak256(preimage)
+            }
+        }
+    }
+}
+
+impl Signer {
+    /// Signs an Ethereum transaction over its correct keccak256 digest and
+    /// returns the RLP/EIP-2718-encoded signed transaction, its hash, and
+    /// the recovered sender address, ready to broadcast directly to a node.
+    pub async fn sign_ethereum_tx(
+        &mut self,
+        tx: EthTransactionRequest,
+        account_id: String,
+    ) -> Result<SignedEthTransaction> {
+        let digest = keccak256(tx.signing_preimage());
+        let signature = self.sign(digest.to_vec(), account_id, Chain::Ethereum).await?;
+        let from = recover_address(&digest, &signature)?;
+        let raw = tx.into_signed_bytes(&signature.r, &signature.s, signature.v as u8);
+        let tx_hash = *keccak256(&raw);
+
+        Ok(SignedEthTransaction {
+            raw,
+            tx_hash,
+            from,
+            signature,
+        })
+    }
+
+    /// Signs any `alloy` typed transaction - legacy, EIP-2930, EIP-1559, or
+    /// EIP-4844 - over its own `signature_hash()` and reassembles it into a
+    /// broadcast-ready [`TxEnvelope`]. Unlike [`Self::sign_ethereum_tx`],
+    /// which only knows the `EthTransactionRequest`/RLP shapes this crate
+    /// hand-rolls, this accepts `alloy_consensus::TypedTransaction` directly
+    /// and lets `alloy` handle each variant's own v/y-parity and encoding
+    /// rules (EIP-155 `v = recovery_id + chain_id*2 + 35` for legacy,
+    /// bare y-parity for every EIP-2718 typed envelope).
+    pub async fn sign_transaction(&mut self, tx: TypedTransaction, account_id: String) -> Result<TxEnvelope> {
+        let signature_hash = tx.signature_hash();
+        let signature = self.sign(signature_hash.to_vec(), account_id, Chain::Ethereum).await?;
+        let sig = AlloySignature::new(
+            U256::from_be_slice(&signature.r),
+            U256::from_be_slice(&signature.s),
+            signature.v != 0,
+        );
+
+        Ok(match tx {
+            TypedTransaction::Legacy(inner) => Signed::new_unchecked(inner, sig, signature_hash).into(),
+            TypedTransaction::Eip2930(inner) => Signed::new_unchecked(inner, sig, signature_hash).into(),
+            TypedTransaction::Eip1559(inner) => Signed::new_unchecked(inner, sig, signature_hash).into(),
+            TypedTransaction::Eip4844(inner) => Signed::new_unchecked(inner, sig, signature_hash).into(),
+            #[allow(unreachable_patterns)]
+            other => anyhow::bail!("unsupported transaction type for MPC signing: {other:?}"),
+        })
+    }
+
+    /// Signs a pre-hashed digest (e.g. an EIP-712 typed-data hash computed
+    /// by the caller) and returns the signature plus the address it
+    /// recovers to. Thin wrapper over
+    /// [`Self::sign_payload`]`(SignPayload::PrehashedDigest(digest), ..)`,
+    /// kept for existing callers that already have a raw digest in hand.
+    pub async fn sign_ethereum_digest(
+        &mut self,
+        digest: [u8; 32],
+        account_id: String,
+    ) -> Result<(SignatureResult, Address)> {
+        self.sign_payload(SignPayload::PrehashedDigest(digest), account_id).await
+    }
+
+    /// Signs `payload` under whatever domain hashing it specifies and
+    /// returns the signature plus the address it recovers to. Covers
+    /// off-chain signing (wallet login via `personal_sign`, EIP-712 typed
+    /// data) alongside `sign_ethereum_tx`'s on-chain transactions.
+    pub async fn sign_payload(
+        &mut self,
+        payload: SignPayload,
+        account_id: String,
+    ) -> Result<(SignatureResult, Address)> {
+        let digest = payload.digest();
+        let signature = self.sign(digest.to_vec(), account_id, Chain::Ethereum).await?;
+        let from = recover_address(&digest, &signature)?;
+        Ok((signature, from))
+    }
+}
+
+/// Recovers the Ethereum address that produced `signature` over `digest`.
+/// Thin wrapper over [`participant::recover_address`] fixed to
+/// `Chain::Ethereum`, since every caller here only ever signs for it.
+fn recover_address(digest: &[u8; 32], signature: &SignatureResult) -> Result<Address> {
+    participant::recover_address(Chain::Ethereum, digest, &signature.r, &signature.s, signature.v)
+}
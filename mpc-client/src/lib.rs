@@ -1,9 +1,24 @@
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{mpsc, oneshot, Mutex};
 use tokio::runtime::Runtime;
+use tokio_util::sync::CancellationToken;
 
+mod chain;
+mod ethereum;
+mod metrics;
+mod rlp;
 mod signer;
-pub use signer::{Signer, SignatureResult as InternalSignatureResult, SignerConfig, KeyShareData};
+pub use ethereum::{AccessListItem, EthTransactionRequest, SignPayload, SignedEthTransaction};
+pub use signer::{
+    ConfigError, ConnectionStatus, Signer, SignatureResult as InternalSignatureResult, SignerConfig,
+    KeyShareData, DEFAULT_CONNECTIVITY_CHECK_INTERVAL_SECS,
+};
+
+// Generated by `build.rs` via `ethers_contract::Abigen` from
+// `contracts/ThresholdVerifier.sol`.
+#[path = "abi/router.rs"]
+mod router;
+pub use router::ThresholdVerifier;
 
 // UniFFI exports
 uniffi::include_scaffolding!("mpc_client");
@@ -27,6 +42,7 @@ pub struct SignatureResult {
     pub r: Vec<u8>,
     pub s: Vec<u8>,
     pub v: u32,
+    pub verified: bool,
 }
 
 impl From<InternalSignatureResult> for SignatureResult {
@@ -35,6 +51,7 @@ impl From<InternalSignatureResult> for SignatureResult {
             r: internal.r,
             s: internal.s,
             v: internal.v,
+            verified: internal.verified,
         }
     }
 }
@@ -55,6 +72,18 @@ impl From<KeyShare> for KeyShareData {
     }
 }
 
+impl KeyShare {
+    /// Derives the Ethereum address this share's public key controls, so
+    /// callers can assert a recovered signer address against it before
+    /// trusting a signature — see [`participant::public_key_to_eth_address`].
+    pub fn eth_address(&self) -> Result<alloy::primitives::Address, anyhow::Error> {
+        let parsed: cggmp21::KeyShare<cggmp21::supported_curves::Secp256k1, cggmp21::security_level::SecurityLevel128> =
+            serde_json::from_str(&self.key_share_data)
+                .map_err(|e| anyhow::anyhow!("Failed to parse key share for {}: {}", self.account_id, e))?;
+        Ok(participant::public_key_to_eth_address(&parsed.shared_public_key))
+    }
+}
+
 /// MPC configuration for UniFFI
 #[derive(Debug, Clone)]
 pub struct MpcConfig {
@@ -70,6 +99,8 @@ pub struct MpcConfig {
     pub threshold: u16,
     pub total_participants: u16,
     pub log_level: String,
+    pub metrics_host: String,
+    pub metrics_port: u16,
 }
 
 impl From<MpcConfig> for SignerConfig {
@@ -87,136 +118,540 @@ impl From<MpcConfig> for SignerConfig {
             threshold: config.threshold,
             total_participants: config.total_participants,
             log_level: config.log_level,
+            connectivity_check_interval_secs: crate::signer::DEFAULT_CONNECTIVITY_CHECK_INTERVAL_SECS,
+            metrics_host: config.metrics_host,
+            metrics_port: config.metrics_port,
+            // No UDL file exists in this snapshot to expose TLS material
+            // through, so `MpcConfig` doesn't carry it; mobile hosts dial
+            // plaintext today, same as before this field existed.
+            tls: None,
+            // Same reasoning as `tls` above: no UDL file to expose a SOCKS5
+            // proxy override through, so mobile hosts always dial directly.
+            proxy: None,
+        }
+    }
+}
+
+/// On-disk mirror of the mutable subset of [`MpcConfig`] that
+/// `MpcSigner::watch_config_file` reloads from. Key shares are intentionally
+/// absent: they can only be supplied at construction time, and
+/// `Signer::reload_config` rejects any attempt to change them at runtime.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct WatchedConfigFile {
+    local_participant_host: String,
+    local_participant_port: u16,
+    local_participant_index: u16,
+    sign_service_host: String,
+    sign_service_port: u16,
+    sse_host: String,
+    sse_port: u16,
+    threshold: u16,
+    total_participants: u16,
+    log_level: String,
+}
+
+impl WatchedConfigFile {
+    /// Builds a full `SignerConfig`, carrying over `key_shares` from
+    /// `current` since they can never be supplied by the watched file.
+    fn into_signer_config(self, current: &SignerConfig) -> SignerConfig {
+        SignerConfig {
+            local_participant_host: self.local_participant_host,
+            local_participant_port: self.local_participant_port,
+            local_participant_index: self.local_participant_index,
+            key_shares: current.key_shares.clone(),
+            sign_gateway_host: self.sign_service_host,
+            sign_gateway_port: self.sign_service_port,
+            sse_host: self.sse_host,
+            sse_port: self.sse_port,
+            threshold: self.threshold,
+            total_participants: self.total_participants,
+            log_level: self.log_level,
+            connectivity_check_interval_secs: current.connectivity_check_interval_secs,
+            metrics_host: current.metrics_host.clone(),
+            metrics_port: current.metrics_port,
+            tls: current.tls.clone(),
+            proxy: current.proxy.clone(),
+        }
+    }
+}
+
+/// Commands accepted by [`run_signer_actor`], the single task that owns the
+/// live `Signer` on behalf of a `MpcSigner`. Each variant carries a oneshot
+/// reply channel so callers can `.await` the result without blocking the
+/// actor loop itself.
+enum ActorCommand {
+    Initialize(oneshot::Sender<Result<(), MpcError>>),
+    Sign {
+        data: Vec<u8>,
+        account_id: String,
+        chain: proto::mpc::Chain,
+        respond_to: oneshot::Sender<Result<InternalSignatureResult, MpcError>>,
+    },
+    Shutdown(oneshot::Sender<()>),
+}
+
+/// Long-lived task that owns `signer` for the lifetime of a `MpcSigner`,
+/// processing one [`ActorCommand`] at a time. An in-flight `Sign` command is
+/// raced against `shutdown` via `tokio::select!` so a shutdown request
+/// aborts it instead of waiting for it to finish, and dropping the caller's
+/// future on the other end of a reply channel (e.g. the host cancelling a
+/// pending request) is simply ignored here rather than tearing anything down.
+async fn run_signer_actor(
+    signer: Arc<Mutex<Option<Signer>>>,
+    mut commands: mpsc::Receiver<ActorCommand>,
+    shutdown: CancellationToken,
+) {
+    loop {
+        tokio::select! {
+            _ = shutdown.cancelled() => {
+                let mut signer_guard = signer.lock().await;
+                if let Some(ref mut signer) = *signer_guard {
+                    let _ = signer.stop_local_participant().await;
+                }
+                break;
+            }
+            cmd = commands.recv() => {
+                let Some(cmd) = cmd else { break };
+                match cmd {
+                    ActorCommand::Initialize(respond_to) => {
+                        let mut signer_guard = signer.lock().await;
+                        let result = match *signer_guard {
+                            Some(ref mut signer) => signer.start_local_participant()
+                                .await
+                                .map_err(|e| MpcError::InitializationError {
+                                    msg: format!("Failed to start local participant: {}", e)
+                                }),
+                            None => Err(MpcError::InitializationError {
+                                msg: "Signer not initialized".to_string()
+                            }),
+                        };
+                        let _ = respond_to.send(result);
+                    }
+                    ActorCommand::Sign { data, account_id, chain, respond_to } => {
+                        let mut signer_guard = signer.lock().await;
+                        let Some(ref mut signer) = *signer_guard else {
+                            let _ = respond_to.send(Err(MpcError::InitializationError {
+                                msg: "Signer not initialized".to_string()
+                            }));
+                            continue;
+                        };
+
+                        tokio::select! {
+                            _ = shutdown.cancelled() => {
+                                let _ = respond_to.send(Err(MpcError::SigningError {
+                                    msg: "Signer is shutting down".to_string()
+                                }));
+                            }
+                            result = signer.sign(data, account_id, chain) => {
+                                let _ = respond_to.send(result.map_err(|e| MpcError::SigningError {
+                                    msg: format!("Signing failed: {}", e)
+                                }));
+                            }
+                        }
+                    }
+                    ActorCommand::Shutdown(respond_to) => {
+                        let mut signer_guard = signer.lock().await;
+                        if let Some(ref mut signer) = *signer_guard {
+                            let _ = signer.stop_local_participant().await;
+                        }
+                        *signer_guard = None;
+                        let _ = respond_to.send(());
+                        break;
+                    }
+                }
+            }
         }
     }
 }
 
+/// A unit of work queued onto [`MpcSigner`]'s dedicated bridge thread: given
+/// the runtime handle that thread owns, run a future to completion and hand
+/// the result back however the caller wired it up (see `block_on_bridge`).
+type BridgeJob = Box<dyn FnOnce(&tokio::runtime::Handle) + Send>;
+
 /// MPC Signer for UniFFI
 pub struct MpcSigner {
     signer: Arc<Mutex<Option<Signer>>>,
     runtime: Arc<Runtime>,
+    /// Channel into the long-lived actor task ([`run_signer_actor`]) that
+    /// owns the `Signer` and serializes access to it.
+    cmd_tx: mpsc::Sender<ActorCommand>,
+    /// Cancelled by `shutdown`/`shutdown_async` to abort an in-flight sign
+    /// round and stop the actor task instead of waiting for it.
+    shutdown_token: CancellationToken,
+    /// Feeds the dedicated bridge thread spawned in `new`; see
+    /// `block_on_bridge`. Every synchronous UniFFI method submits its async
+    /// work here instead of spawning a fresh OS thread and runtime per call.
+    bridge_tx: std::sync::mpsc::Sender<BridgeJob>,
 }
 
 impl MpcSigner {
     /// Create a new MPC signer with configuration
     pub fn new(config: MpcConfig) -> Result<Self, MpcError> {
         let signer_config: SignerConfig = config.clone().into();
-        let signer_mutex = Arc::new(Mutex::new(None));
-        let signer_mutex_clone = signer_mutex.clone();
-
-        // Create runtime in a separate thread to avoid nesting issues
-        let (runtime, result) = std::thread::scope(|s| {
-            let handle = s.spawn(|| {
-                let runtime = Runtime::new()
-                    .map_err(|e| MpcError::InitializationError {
-                        msg: format!("Failed to create tokio runtime: {}", e)
-                    })?;
-
-                let result = runtime.block_on(async move {
-                    let signer = Signer::new(signer_config)
-                        .await
-                        .map_err(|e| MpcError::InitializationError {
-                            msg: format!("Failed to create signer: {}", e)
-                        })?;
-
-                    let mut signer_guard = signer_mutex_clone.lock().await;
-                    *signer_guard = Some(signer);
-
-                    Ok::<(), MpcError>(())
-                });
-
-                Ok::<(Runtime, Result<(), MpcError>), MpcError>((runtime, result))
-            });
-
-            handle.join().map_err(|_| MpcError::InitializationError {
-                msg: "Thread panicked during initialization".to_string()
-            })?
+
+        let runtime = Runtime::new().map_err(|e| MpcError::InitializationError {
+            msg: format!("Failed to create tokio runtime: {}", e),
         })?;
+        let handle = runtime.handle().clone();
+
+        let signer_mutex = Arc::new(Mutex::new(None));
+        let (cmd_tx, cmd_rx) = mpsc::channel(32);
+        let shutdown_token = CancellationToken::new();
+        runtime.spawn(run_signer_actor(signer_mutex.clone(), cmd_rx, shutdown_token.clone()));
 
-        result?;
+        // One dedicated background thread owns this runtime for the
+        // signer's whole lifetime: every synchronous method below submits
+        // its async work here via `block_on_bridge` rather than spawning a
+        // fresh OS thread (and blocking on a brand new runtime) per call.
+        let (bridge_tx, bridge_rx) = std::sync::mpsc::channel::<BridgeJob>();
+        let bridge_handle = handle.clone();
+        std::thread::Builder::new()
+            .name("mpc-signer-bridge".to_string())
+            .spawn(move || {
+                for job in bridge_rx {
+                    job(&bridge_handle);
+                }
+            })
+            .map_err(|e| MpcError::InitializationError {
+                msg: format!("Failed to spawn bridge thread: {}", e),
+            })?;
 
-        Ok(Self {
-            signer: signer_mutex,
+        let this = Self {
+            signer: signer_mutex.clone(),
             runtime: Arc::new(runtime),
-        })
+            cmd_tx,
+            shutdown_token,
+            bridge_tx,
+        };
+
+        this.block_on_bridge(async move {
+            let signer = Signer::new(signer_config, handle)
+                .await
+                .map_err(|e| MpcError::InitializationError {
+                    msg: format!("Failed to create signer: {}", e),
+                })?;
+            *signer_mutex.lock().await = Some(signer);
+            Ok::<(), MpcError>(())
+        })?;
+
+        Ok(this)
     }
 
-    /// Initialize the MPC signer (start local participant)
+    /// Runs `fut` to completion on the dedicated bridge thread spawned in
+    /// `new`, blocking the calling thread until it's done. `fut` must be
+    /// `'static` and own everything it touches (no borrows of `self`), since
+    /// it's sent across to a thread that outlives any single call.
+    fn block_on_bridge<F>(&self, fut: F) -> F::Output
+    where
+        F: std::future::Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (result_tx, result_rx) = std::sync::mpsc::channel();
+        let job: BridgeJob = Box::new(move |handle| {
+            let _ = result_tx.send(handle.block_on(fut));
+        });
+        self.bridge_tx.send(job).expect("bridge thread is still running");
+        result_rx.recv().expect("bridge thread dropped the result sender")
+    }
+
+    /// Async variant of [`Self::initialize`], exported through UniFFI's
+    /// async support. Runs on the actor task so it can be raced against
+    /// other in-flight commands instead of blocking a dedicated thread.
+    pub async fn initialize_async(&self) -> Result<(), MpcError> {
+        Self::do_initialize(self.cmd_tx.clone()).await
+    }
+
+    /// Initialize the MPC signer (start local participant). Thin wrapper
+    /// over [`Self::do_initialize`], run on the bridge thread spawned in
+    /// `new`, kept for callers that aren't async yet.
     pub fn initialize(&self) -> Result<(), MpcError> {
-        let signer_mutex = self.signer.clone();
-        let runtime = self.runtime.clone();
-
-        std::thread::scope(|s| {
-            let handle = s.spawn(move || {
-                runtime.block_on(async move {
-                    let mut signer_guard = signer_mutex.lock().await;
-                    if let Some(ref mut signer) = *signer_guard {
-                        signer.start_local_participant()
-                            .await
-                            .map_err(|e| MpcError::InitializationError {
-                                msg: format!("Failed to start local participant: {}", e)
-                            })
-                    } else {
-                        Err(MpcError::InitializationError {
-                            msg: "Signer not initialized".to_string()
-                        })
-                    }
-                })
-            });
+        self.block_on_bridge(Self::do_initialize(self.cmd_tx.clone()))
+    }
+
+    async fn do_initialize(cmd_tx: mpsc::Sender<ActorCommand>) -> Result<(), MpcError> {
+        let (respond_to, response) = oneshot::channel();
+        cmd_tx.send(ActorCommand::Initialize(respond_to)).await
+            .map_err(|_| MpcError::InitializationError {
+                msg: "Signer actor has shut down".to_string()
+            })?;
+        response.await.map_err(|_| MpcError::InitializationError {
+            msg: "Signer actor dropped the request".to_string()
+        })?
+    }
+
+    /// Apply a new config to the running signer in place, without a full
+    /// re-initialization. Fields that would invalidate the live
+    /// cryptographic state (threshold, participant count, local index, key
+    /// shares) are rejected with `MpcError::ConfigError`; everything else
+    /// (remote host/port, SSE endpoint, log level) is swapped atomically.
+    pub fn reload_config(&self, new_config: MpcConfig) -> Result<(), MpcError> {
+        let signer_config: SignerConfig = new_config.into();
+        self.block_on_bridge(Self::do_reload_config(self.signer.clone(), signer_config))
+    }
+
+    async fn do_reload_config(signer: Arc<Mutex<Option<Signer>>>, new_config: SignerConfig) -> Result<(), MpcError> {
+        let mut signer_guard = signer.lock().await;
+        if let Some(ref mut signer) = *signer_guard {
+            signer.reload_config(new_config).map_err(|e| MpcError::ConfigError {
+                msg: e.to_string(),
+            })
+        } else {
+            Err(MpcError::InitializationError {
+                msg: "Signer not initialized".to_string()
+            })
+        }
+    }
 
-            handle.join().map_err(|_| MpcError::InitializationError {
-                msg: "Thread panicked during initialization".to_string()
-            })?
-        })
+    /// Async variant of [`Self::sign_data`], exported through UniFFI's
+    /// async support. Runs on the actor task, so cancelling the returned
+    /// future (e.g. by dropping it) simply abandons the reply without
+    /// touching the in-flight round, and a concurrent `shutdown_async` call
+    /// aborts it instead. Always signs for `Chain::Ethereum`; the UniFFI
+    /// surface doesn't expose chain selection yet, unlike
+    /// `mpc_client::Signer::sign` (see `crate::chain`) which non-FFI callers
+    /// like `ethereum::sign_ethereum_tx` already drive directly.
+    pub async fn sign_data_async(&self, data: Vec<u8>, account_id: String) -> Result<SignatureResult, MpcError> {
+        Self::do_sign(self.cmd_tx.clone(), data, account_id, proto::mpc::Chain::Ethereum).await
     }
 
-    /// Sign data using MPC with account_id
+    /// Sign data using MPC with account_id. Thin wrapper over
+    /// [`Self::do_sign`], run on the bridge thread spawned in `new`, kept
+    /// for callers that aren't async yet.
     pub fn sign_data(&self, data: Vec<u8>, account_id: String) -> Result<SignatureResult, MpcError> {
-        let signer_mutex = self.signer.clone();
-        let runtime = self.runtime.clone();
-
-        std::thread::scope(|s| {
-            let handle = s.spawn(move || {
-                runtime.block_on(async move {
-                    let mut signer_guard = signer_mutex.lock().await;
-                    if let Some(ref mut signer) = *signer_guard {
-                        let result = signer.sign(data, account_id)
-                            .await
-                            .map_err(|e| MpcError::SigningError {
-                                msg: format!("Signing failed: {}", e)
-                            })?;
-                        Ok(result.into())
-                    } else {
-                        Err(MpcError::InitializationError {
-                            msg: "Signer not initialized".to_string()
-                        })
-                    }
-                })
-            });
+        self.block_on_bridge(Self::do_sign(self.cmd_tx.clone(), data, account_id, proto::mpc::Chain::Ethereum))
+    }
 
-            handle.join().map_err(|_| MpcError::SigningError {
-                msg: "Thread panicked during signing".to_string()
-            })?
-        })
+    async fn do_sign(
+        cmd_tx: mpsc::Sender<ActorCommand>,
+        data: Vec<u8>,
+        account_id: String,
+        chain: proto::mpc::Chain,
+    ) -> Result<SignatureResult, MpcError> {
+        let (respond_to, response) = oneshot::channel();
+        cmd_tx.send(ActorCommand::Sign { data, account_id, chain, respond_to }).await
+            .map_err(|_| MpcError::SigningError {
+                msg: "Signer actor has shut down".to_string()
+            })?;
+        let result = response.await.map_err(|_| MpcError::SigningError {
+            msg: "Signer actor dropped the request".to_string()
+        })??;
+        Ok(result.into())
     }
 
-    /// Shutdown the MPC signer
-    pub fn shutdown(&self) {
-        let signer_mutex = self.signer.clone();
-        let runtime = self.runtime.clone();
-
-        let _ = std::thread::scope(|s| {
-            let handle = s.spawn(move || {
-                runtime.block_on(async move {
-                    let mut signer_guard = signer_mutex.lock().await;
-                    if let Some(ref mut signer) = *signer_guard {
-                        let _ = signer.stop_local_participant().await;
+    /// Spawns a background task that polls `path` for a YAML config every
+    /// `poll_interval_secs` and applies it via `reload_config` whenever its
+    /// contents change, debouncing so a burst of rapid edits only triggers
+    /// one reload once the file settles. Parse errors and rejected
+    /// (immutable-field) reloads are logged and otherwise ignored so a
+    /// momentarily invalid file doesn't bring down the watcher.
+    pub fn watch_config_file(&self, path: String, poll_interval_secs: u64) {
+        let signer = self.signer.clone();
+
+        // Spawned straight onto the shared runtime rather than a dedicated
+        // OS thread: this loop never returns a result the caller waits on,
+        // so it doesn't need `block_on_bridge`.
+        self.runtime.spawn(async move {
+            let mut last_content: Option<String> = None;
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(poll_interval_secs.max(1)));
+
+            loop {
+                interval.tick().await;
+
+                let content = match tokio::fs::read_to_string(&path).await {
+                    Ok(content) => content,
+                    Err(e) => {
+                        log::warn!("Config watcher: failed to read {}: {}", path, e);
+                        continue;
+                    }
+                };
+
+                if last_content.as_deref() == Some(content.as_str()) {
+                    continue;
+                }
+                last_content = Some(content.clone());
+
+                let watched: WatchedConfigFile = match serde_yaml::from_str(&content) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        log::warn!("Config watcher: failed to parse {}: {}, keeping current config", path, e);
+                        continue;
+                    }
+                };
+
+                let mut signer_guard = signer.lock().await;
+                if let Some(ref mut signer) = *signer_guard {
+                    let new_config = watched.into_signer_config(signer.config());
+                    match signer.reload_config(new_config) {
+                        Ok(()) => info!("Config watcher: applied updated config from {}", path),
+                        Err(e) => log::warn!("Config watcher: rejected update from {}: {}", path, e),
                     }
-                    *signer_guard = None;
-                })
-            });
+                }
+            }
+        });
+    }
+
+    /// Spawns a background supervisor that probes connectivity every
+    /// `base_interval_secs` and reconnects on failure, doubling the probe
+    /// interval (capped at `base_interval_secs * 16`) while disconnected and
+    /// resetting it back to `base_interval_secs` once connectivity returns.
+    pub fn start_supervisor(&self, base_interval_secs: u64) {
+        let signer = self.signer.clone();
+
+        // Spawned straight onto the shared runtime rather than a dedicated
+        // OS thread, same reasoning as `watch_config_file`.
+        self.runtime.spawn(async move {
+            let base = std::time::Duration::from_secs(base_interval_secs.max(1));
+            let max = base * 16;
+            let mut current = base;
+
+            loop {
+                tokio::time::sleep(current).await;
 
-            handle.join()
+                let mut signer_guard = signer.lock().await;
+                let Some(ref mut signer) = *signer_guard else {
+                    continue;
+                };
+
+                if let Err(e) = signer.check_and_reconnect().await {
+                    error!("Connectivity supervisor error: {}", e);
+                }
+
+                current = match signer.connection_status() {
+                    ConnectionStatus::Connected => base,
+                    _ => std::cmp::min(current * 2, max),
+                };
+            }
         });
     }
+
+    /// Current liveness of the signer's gateway/SSE connections.
+    pub fn connection_status(&self) -> Result<ConnectionStatus, MpcError> {
+        self.block_on_bridge(Self::do_connection_status(self.signer.clone()))
+    }
+
+    async fn do_connection_status(signer: Arc<Mutex<Option<Signer>>>) -> Result<ConnectionStatus, MpcError> {
+        let signer_guard = signer.lock().await;
+        match *signer_guard {
+            Some(ref signer) => Ok(signer.connection_status()),
+            None => Err(MpcError::InitializationError {
+                msg: "Signer not initialized".to_string()
+            }),
+        }
+    }
+
+    /// Loads an additional account's key share into the running local
+    /// participant, so mobile hosts can provision new accounts without
+    /// tearing the signer down and recreating it with an updated
+    /// `MpcConfig`. Mirrors [`Signer::add_account_key_share`].
+    pub fn add_account_key_share(&self, key_share: KeyShare) -> Result<(), MpcError> {
+        self.block_on_bridge(Self::do_add_account_key_share(self.signer.clone(), key_share.into()))
+    }
+
+    async fn do_add_account_key_share(
+        signer: Arc<Mutex<Option<Signer>>>,
+        key_share: KeyShareData,
+    ) -> Result<(), MpcError> {
+        let mut signer_guard = signer.lock().await;
+        match *signer_guard {
+            Some(ref mut signer) => signer.add_account_key_share(key_share).await.map_err(|e| MpcError::ConfigError {
+                msg: e.to_string(),
+            }),
+            None => Err(MpcError::InitializationError {
+                msg: "Signer not initialized".to_string()
+            }),
+        }
+    }
+
+    /// Drops an account's key share from the running local participant, e.g.
+    /// when a mobile host gives up custody of that account. Returns `true`
+    /// if an entry was actually removed. Mirrors
+    /// [`Signer::remove_account_key_share`].
+    pub fn remove_account_key_share(&self, account_id: String) -> Result<bool, MpcError> {
+        self.block_on_bridge(Self::do_remove_account_key_share(self.signer.clone(), account_id))
+    }
+
+    async fn do_remove_account_key_share(
+        signer: Arc<Mutex<Option<Signer>>>,
+        account_id: String,
+    ) -> Result<bool, MpcError> {
+        let mut signer_guard = signer.lock().await;
+        match *signer_guard {
+            Some(ref mut signer) => signer.remove_account_key_share(&account_id).await.map_err(|e| MpcError::ConfigError {
+                msg: e.to_string(),
+            }),
+            None => Err(MpcError::InitializationError {
+                msg: "Signer not initialized".to_string()
+            }),
+        }
+    }
+
+    /// `account_id`s currently available on the running local participant.
+    /// Mirrors [`Signer::list_account_ids`].
+    pub fn list_account_ids(&self) -> Result<Vec<String>, MpcError> {
+        self.block_on_bridge(Self::do_list_account_ids(self.signer.clone()))
+    }
+
+    async fn do_list_account_ids(signer: Arc<Mutex<Option<Signer>>>) -> Result<Vec<String>, MpcError> {
+        let signer_guard = signer.lock().await;
+        match *signer_guard {
+            Some(ref signer) => signer.list_account_ids().await.map_err(|e| MpcError::ConfigError {
+                msg: e.to_string(),
+            }),
+            None => Err(MpcError::InitializationError {
+                msg: "Signer not initialized".to_string()
+            }),
+        }
+    }
+
+    /// Proactively re-randomizes `account_id`'s key share against a
+    /// suspected compromise, without changing its address. Every other
+    /// participant must call this at the same time with the same
+    /// `execution_id` (coordinated out of band, the same way DKG's
+    /// `execution_id` is). Mirrors [`Signer::refresh_account_key_share`].
+    pub fn refresh_account_key_share(&self, account_id: String, execution_id: String, output_path: String) -> Result<(), MpcError> {
+        self.block_on_bridge(Self::do_refresh_account_key_share(self.signer.clone(), account_id, execution_id, output_path))
+    }
+
+    async fn do_refresh_account_key_share(
+        signer: Arc<Mutex<Option<Signer>>>,
+        account_id: String,
+        execution_id: String,
+        output_path: String,
+    ) -> Result<(), MpcError> {
+        let mut signer_guard = signer.lock().await;
+        match *signer_guard {
+            Some(ref mut signer) => signer
+                .refresh_account_key_share(&account_id, execution_id.as_bytes(), &output_path)
+                .await
+                .map_err(|e| MpcError::ConfigError { msg: e.to_string() }),
+            None => Err(MpcError::InitializationError {
+                msg: "Signer not initialized".to_string()
+            }),
+        }
+    }
+
+    /// Async variant of [`Self::shutdown`], exported through UniFFI's async
+    /// support. Cancels `shutdown_token` first so an in-flight sign round
+    /// aborts immediately instead of being awaited to completion, then waits
+    /// for the actor task to tear down the local participant and exit.
+    pub async fn shutdown_async(&self) {
+        Self::do_shutdown(self.cmd_tx.clone(), self.shutdown_token.clone()).await
+    }
+
+    /// Shutdown the MPC signer. Thin wrapper over [`Self::do_shutdown`], run
+    /// on the bridge thread spawned in `new`, kept for callers that aren't
+    /// async yet.
+    pub fn shutdown(&self) {
+        self.block_on_bridge(Self::do_shutdown(self.cmd_tx.clone(), self.shutdown_token.clone()))
+    }
+
+    async fn do_shutdown(cmd_tx: mpsc::Sender<ActorCommand>, shutdown_token: CancellationToken) {
+        shutdown_token.cancel();
+
+        let (respond_to, response) = oneshot::channel();
+        if cmd_tx.send(ActorCommand::Shutdown(respond_to)).await.is_ok() {
+            let _ = response.await;
+        }
+    }
 }
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Upper bounds (in milliseconds) of the signing-round latency histogram's
+/// buckets, matching Prometheus's own `le` bucket convention (each bucket
+/// counts observations `<= le`); an implicit `+Inf` bucket counting every
+/// observation is added on render.
+const LATENCY_BUCKETS_MS: &[f64] = &[50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0];
+
+/// Prometheus-style counters and a latency histogram for a `Signer`'s
+/// signing rounds and participant connectivity. Kept dependency-free (no
+/// `prometheus` crate) since exposing this handful of series doesn't need a
+/// full metrics framework; [`SignerMetrics::render`] writes the text
+/// exposition format by hand.
+#[derive(Default)]
+pub struct SignerMetrics {
+    rounds_started: AtomicU64,
+    rounds_succeeded: AtomicU64,
+    rounds_failed: AtomicU64,
+    /// Parallel to `LATENCY_BUCKETS_MS`, plus one trailing `+Inf` bucket.
+    round_latency_buckets: Vec<AtomicU64>,
+    round_latency_sum_ms: AtomicU64,
+    round_latency_count: AtomicU64,
+    active_rounds: AtomicU64,
+    reconnects: AtomicU64,
+    /// Per-participant (keyed by URI) success/failure counts.
+    participant_successes: Mutex<HashMap<String, u64>>,
+    participant_failures: Mutex<HashMap<String, u64>>,
+    /// gRPC error counts keyed by `tonic::Code` name (e.g. "unavailable",
+    /// "deadline_exceeded").
+    grpc_errors_by_code: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl SignerMetrics {
+    pub fn new() -> Self {
+        Self {
+            round_latency_buckets: (0..LATENCY_BUCKETS_MS.len() + 1).map(|_| AtomicU64::new(0)).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn record_participant_result(&self, uri: &str, success: bool) {
+        let mut map = if success {
+            self.participant_successes.lock().expect("participant_successes lock poisoned")
+        } else {
+            self.participant_failures.lock().expect("participant_failures lock poisoned")
+        };
+        *map.entry(uri.to_string()).or_insert(0) += 1;
+    }
+
+    pub fn record_grpc_error(&self, status: &tonic::Status) {
+        let code = grpc_code_label(status.code());
+        let mut map = self.grpc_errors_by_code.lock().expect("grpc_errors_by_code lock poisoned");
+        *map.entry(code).or_insert(0) += 1;
+    }
+
+    pub fn record_reconnect(&self) {
+        self.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Renders every series in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP mpc_signer_rounds_started_total Signing rounds started.\n");
+        out.push_str("# TYPE mpc_signer_rounds_started_total counter\n");
+        out.push_str(&format!("mpc_signer_rounds_started_total {}\n", self.rounds_started.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP mpc_signer_rounds_succeeded_total Signing rounds that reached quorum.\n");
+        out.push_str("# TYPE mpc_signer_rounds_succeeded_total counter\n");
+        out.push_str(&format!("mpc_signer_rounds_succeeded_total {}\n", self.rounds_succeeded.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP mpc_signer_rounds_failed_total Signing rounds that failed to reach quorum.\n");
+        out.push_str("# TYPE mpc_signer_rounds_failed_total counter\n");
+        out.push_str(&format!("mpc_signer_rounds_failed_total {}\n", self.rounds_failed.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP mpc_signer_active_rounds In-flight signing rounds.\n");
+        out.push_str("# TYPE mpc_signer_active_rounds gauge\n");
+        out.push_str(&format!("mpc_signer_active_rounds {}\n", self.active_rounds.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP mpc_signer_reconnects_total Participant channel rebuilds (connectivity checker and gateway reconnect).\n");
+        out.push_str("# TYPE mpc_signer_reconnects_total counter\n");
+        out.push_str(&format!("mpc_signer_reconnects_total {}\n", self.reconnects.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP mpc_signer_round_latency_ms Time from a sign() call starting to its first successful response, in milliseconds.\n");
+        out.push_str("# TYPE mpc_signer_round_latency_ms histogram\n");
+        let mut cumulative = 0u64;
+        for (i, bound) in LATENCY_BUCKETS_MS.iter().enumerate() {
+            cumulative += self.round_latency_buckets[i].load(Ordering::Relaxed);
+            out.push_str(&format!("mpc_signer_round_latency_ms_bucket{{le=\"{}\"}} {}\n", bound, cumulative));
+        }
+        cumulative += self.round_latency_buckets[LATENCY_BUCKETS_MS.len()].load(Ordering::Relaxed);
+        out.push_str(&format!("mpc_signer_round_latency_ms_bucket{{le=\"+Inf\"}} {}\n", cumulative));
+        out.push_str(&format!("mpc_signer_round_latency_ms_sum {}\n", self.round_latency_sum_ms.load(Ordering::Relaxed)));
+        out.push_str(&format!("mpc_signer_round_latency_ms_count {}\n", self.round_latency_count.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP mpc_signer_participant_results_total Per-participant sign() outcomes, keyed by URI and result.\n");
+        out.push_str("# TYPE mpc_signer_participant_results_total counter\n");
+        for (uri, count) in self.participant_successes.lock().expect("participant_successes lock poisoned").iter() {
+            out.push_str(&format!("mpc_signer_participant_results_total{{uri=\"{}\",result=\"success\"}} {}\n", uri, count));
+        }
+        for (uri, count) in self.participant_failures.lock().expect("participant_failures lock poisoned").iter() {
+            out.push_str(&format!("mpc_signer_participant_results_total{{uri=\"{}\",result=\"failure\"}} {}\n", uri, count));
+        }
+
+        out.push_str("# HELP mpc_signer_grpc_errors_total gRPC errors from participants, keyed by status code.\n");
+        out.push_str("# TYPE mpc_signer_grpc_errors_total counter\n");
+        for (code, count) in self.grpc_errors_by_code.lock().expect("grpc_errors_by_code lock poisoned").iter() {
+            out.push_str(&format!("mpc_signer_grpc_errors_total{{code=\"{}\"}} {}\n", code, count));
+        }
+
+        out
+    }
+
+    /// Serves `render()` as `text/plain` over `host:port` to any connection,
+    /// regardless of the requested path, until the task is aborted. Intended
+    /// to be spawned once alongside the local participant server.
+    pub async fn serve(self: std::sync::Arc<Self>, host: &str, port: u16) -> std::io::Result<()> {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind((host, port)).await?;
+        log::info!("Metrics endpoint listening on {}:{}/metrics", host, port);
+        loop {
+            let (mut socket, _) = listener.accept().await?;
+            let metrics = self.clone();
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                // Best-effort: drain whatever the client sent so far and
+                // respond with the current snapshot regardless of path.
+                let _ = socket.read(&mut buf).await;
+                let body = metrics.render();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            });
+        }
+    }
+}
+
+/// Maps a `tonic::Code` to the lower-snake-case label Prometheus exporters
+/// conventionally use for gRPC status codes.
+fn grpc_code_label(code: tonic::Code) -> &'static str {
+    use tonic::Code::*;
+    match code {
+        Ok => "ok",
+        Cancelled => "cancelled",
+        Unknown => "unknown",
+        InvalidArgument => "invalid_argument",
+        DeadlineExceeded => "deadline_exceeded",
+        NotFound => "not_found",
+        AlreadyExists => "already_exists",
+        PermissionDenied => "permission_denied",
+        ResourceExhausted => "resource_exhausted",
+        FailedPrecondition => "failed_precondition",
+        Aborted => "aborted",
+        OutOfRange => "out_of_range",
+        Unimplemented => "unimplemented",
+        Internal => "internal",
+        Unavailable => "unavailable",
+        DataLoss => "data_loss",
+        Unauthenticated => "unauthenticated",
+    }
+}
+
+/// Records a signing round's outcome and latency on drop, so every `return`
+/// path out of `Signer::sign` (success, partial quorum, early validation
+/// error) reports consistently without repeating bookkeeping at each one.
+/// Holds its own `Arc` clone (rather than borrowing `Signer::metrics`) so it
+/// doesn't tie up a borrow of `Signer` across the `&mut self` calls the rest
+/// of `sign()` makes.
+pub struct RoundGuard {
+    metrics: Arc<SignerMetrics>,
+    started_at: std::time::Instant,
+    finished: bool,
+}
+
+impl RoundGuard {
+    /// Starts timing a round against `metrics`, bumping `rounds_started` and
+    /// the active-round gauge immediately.
+    pub fn start(metrics: Arc<SignerMetrics>) -> Self {
+        metrics.rounds_started.fetch_add(1, Ordering::Relaxed);
+        metrics.active_rounds.fetch_add(1, Ordering::Relaxed);
+        Self {
+            metrics,
+            started_at: std::time::Instant::now(),
+            finished: false,
+        }
+    }
+
+    /// Records the round as succeeded or failed. If never called, `Drop`
+    /// records a failure, since every successful path should call this
+    /// explicitly.
+    pub fn finish(mut self, success: bool) {
+        self.record(success);
+        self.finished = true;
+    }
+
+    fn record(&self, success: bool) {
+        let elapsed_ms = self.started_at.elapsed().as_millis() as u64;
+        self.metrics.active_rounds.fetch_sub(1, Ordering::Relaxed);
+        if success {
+            self.metrics.rounds_succeeded.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.metrics.rounds_failed.fetch_add(1, Ordering::Relaxed);
+        }
+
+        self.metrics.round_latency_sum_ms.fetch_add(elapsed_ms, Ordering::Relaxed);
+        self.metrics.round_latency_count.fetch_add(1, Ordering::Relaxed);
+        let bucket_index = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| (elapsed_ms as f64) <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.metrics.round_latency_buckets[bucket_index].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl Drop for RoundGuard {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.record(false);
+        }
+    }
+}
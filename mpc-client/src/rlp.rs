@@ -0,0 +1,90 @@
+//! Minimal RLP encoder — just enough to assemble a signed Ethereum
+//! transaction envelope in [`ethereum`](crate::ethereum) without pulling in
+//! a dedicated RLP crate.
+
+/// One RLP-encodable value: either a byte string or a list of items.
+pub enum RlpItem {
+    Bytes(Vec<u8>),
+    List(Vec<RlpItem>),
+}
+
+impl RlpItem {
+    /// A big-endian unsigned integer, RLP-encoded as its minimal byte
+    /// string (no leading zero bytes, and the empty string for zero).
+    pub fn uint(value: u128) -> Self {
+        let bytes = value.to_be_bytes();
+        let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+        Self::Bytes(bytes[first_nonzero..].to_vec())
+    }
+
+    pub fn bytes(bytes: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes(bytes.into())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        match self {
+            RlpItem::Bytes(bytes) => encode_bytes(bytes),
+            RlpItem::List(items) => {
+                let payload: Vec<u8> = items.iter().flat_map(|item| item.encode()).collect();
+                encode_length(payload.len(), 0xc0)
+                    .into_iter()
+                    .chain(payload)
+                    .collect()
+            }
+        }
+    }
+}
+
+fn encode_length(len: usize, offset: u8) -> Vec<u8> {
+    if len < 56 {
+        vec![offset + len as u8]
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let first_nonzero = len_bytes
+            .iter()
+            .position(|&b| b != 0)
+            .unwrap_or(len_bytes.len() - 1);
+        let len_bytes = &len_bytes[first_nonzero..];
+        let mut out = vec![offset + 55 + len_bytes.len() as u8];
+        out.extend_from_slice(len_bytes);
+        out
+    }
+}
+
+fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        vec![bytes[0]]
+    } else {
+        let mut out = encode_length(bytes.len(), 0x80);
+        out.extend_from_slice(bytes);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_empty_bytes_as_single_byte() {
+        assert_eq!(RlpItem::bytes(Vec::new()).encode(), vec![0x80]);
+    }
+
+    #[test]
+    fn encodes_single_small_byte_as_itself() {
+        assert_eq!(RlpItem::bytes(vec![0x01]).encode(), vec![0x01]);
+    }
+
+    #[test]
+    fn encodes_uint_without_leading_zeros() {
+        assert_eq!(RlpItem::uint(0).encode(), vec![0x80]);
+        assert_eq!(RlpItem::uint(15).encode(), vec![0x0f]);
+        assert_eq!(RlpItem::uint(1024).encode(), vec![0x82, 0x04, 0x00]);
+    }
+
+    #[test]
+    fn encodes_list_of_short_items() {
+        let list = RlpItem::List(vec![RlpItem::uint(1), RlpItem::uint(2)]);
+        assert_eq!(list.encode(), vec![0xc2, 0x01, 0x02]);
+    }
+}
@@ -1,23 +1,29 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
-use log::{info, error};
+use log::{info, error, warn};
+use thiserror::Error;
+use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 use uuid::Uuid;
-use futures::future::join_all;
 use rand::{thread_rng, Rng};
 
-use participant::ParticipantServer;
+use participant::{ParticipantServer, ProxyConfig, TlsConfig};
 use proto::mpc::participant_client::ParticipantClient;
 use proto::mpc::{SignMessage, Chain};
 use tonic::transport::Channel;
 use cggmp21::KeyShare;
 use cggmp21::security_level::SecurityLevel128;
 use cggmp21::supported_curves::Secp256k1;
+use alloy::signers::k256::ecdsa::VerifyingKey;
+
+use crate::chain::chain_signer;
+use crate::metrics::{RoundGuard, SignerMetrics};
 
 /// Key share data structure for iOS client
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct KeyShareData {
     pub account_id: String,
     pub key_share_data: String, // JSON-formatted key share data
@@ -37,6 +43,45 @@ pub struct SignerConfig {
     pub threshold: u16,
     pub total_participants: u16,
     pub log_level: String,
+    /// How often the background connectivity checker re-dials each
+    /// participant and rebuilds its channel if needed; see
+    /// [`Signer::check_and_reconnect`] for the gateway/SSE equivalent this
+    /// complements. `DEFAULT_CONNECTIVITY_CHECK_INTERVAL_SECS` if unsure.
+    pub connectivity_check_interval_secs: u64,
+    /// Host the Prometheus-style `/metrics` HTTP endpoint binds to; see
+    /// [`crate::metrics::SignerMetrics`]. Started alongside the local
+    /// participant server in `start_local_participant`.
+    pub metrics_host: String,
+    /// Port the `/metrics` endpoint listens on.
+    pub metrics_port: u16,
+    /// Mutual-TLS material for every gRPC/HTTP connection this signer makes
+    /// or serves: the sign-gateway dial, the local participant's own gRPC
+    /// endpoint and SSE link, and the background connectivity/reconnect
+    /// probes. `None` keeps today's plaintext behavior; see
+    /// [`participant::TlsConfig`] for the certificate/CA fields.
+    pub tls: Option<TlsConfig>,
+    /// Routes every gRPC connection this signer makes (the sign-gateway
+    /// dial and the local participant's own gRPC endpoint) through a SOCKS5
+    /// proxy, e.g. a local Tor daemon, instead of dialing directly. `None`
+    /// keeps today's direct-dial behavior; see [`participant::ProxyConfig`].
+    /// Not combined with `tls` on the same dial yet — see
+    /// [`participant::ProxyConfig::connect_channel`].
+    pub proxy: Option<ProxyConfig>,
+}
+
+/// Default for [`SignerConfig::connectivity_check_interval_secs`].
+pub const DEFAULT_CONNECTIVITY_CHECK_INTERVAL_SECS: u64 = 10;
+
+/// Errors from [`Signer::reload_config`] when a new config is rejected
+/// because it would invalidate the cryptographic state set up at `new`.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("field '{field}' cannot change at runtime (current: {current}, requested: {requested})")]
+    ImmutableField {
+        field: String,
+        current: String,
+        requested: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -44,24 +89,82 @@ pub struct SignatureResult {
     pub r: Vec<u8>,
     pub s: Vec<u8>,
     pub v: u32,
+    /// Whether `sign()` recovered this signature against the account's
+    /// shared public key before returning it. Always `true` in practice:
+    /// `sign()` discards non-verifying responses and tries the next one
+    /// instead of returning them, so this exists to give callers an
+    /// explicit end-to-end guarantee rather than an implicit one.
+    pub verified: bool,
 }
 
 
+/// Liveness of the signer's connections to the sign-gateway and (if started)
+/// the local participant's SSE link, as tracked by [`Signer::check_and_reconnect`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    Connected,
+    Reconnecting { attempts: u32 },
+    Disconnected,
+}
+
+/// After this many failed reconnect attempts in a row, `check_and_reconnect`
+/// gives up and reports `Disconnected` instead of continuing to retry.
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+
+/// A connected participant client paired with the URI it was dialed from, so
+/// the background connectivity checker can redial the same address when the
+/// channel goes bad without the caller having to track URIs separately.
+#[derive(Clone)]
+struct ParticipantEntry {
+    uri: String,
+    client: ParticipantClient<Channel>,
+    /// Consecutive `sign()` failures for this participant, reset to 0 on its
+    /// next success. Used to dispatch to the likeliest-healthy participants
+    /// first when the pool is larger than `threshold`; see `Signer::sign`.
+    failure_count: u32,
+}
+
 pub struct Signer {
     config: SignerConfig,
     local_participant_server: Option<ParticipantServer>,
     local_participant_handle: Option<JoinHandle<Result<()>>>,
-    all_participant_clients: Vec<ParticipantClient<Channel>>,
+    /// Shared with the background connectivity checker task, so `sign()`
+    /// always reads the freshest set of clients even if one was just
+    /// rebuilt after a reconnect.
+    participants: Arc<RwLock<Vec<ParticipantEntry>>>,
+    /// Handle to the periodic connectivity checker spawned in `new`; aborted
+    /// on `stop_local_participant`/`Drop` alongside the local participant
+    /// task since neither has more work to do once the signer is gone.
+    connectivity_checker_handle: Option<JoinHandle<()>>,
+    /// Prometheus-style counters/histogram for signing rounds and
+    /// participant connectivity; see [`crate::metrics::SignerMetrics`].
+    metrics: Arc<SignerMetrics>,
+    /// Handle to the `/metrics` HTTP server spawned alongside the local
+    /// participant in `start_local_participant`; aborted on
+    /// `stop_local_participant`/`Drop`.
+    metrics_server_handle: Option<JoinHandle<std::io::Result<()>>>,
     /// Instance unique identifier (high 16 bits of tx_id)
     /// Combines timestamp and random number to avoid collision across instances
     instance_id: u16,
     /// Incremental counter within this instance (low 16 bits of tx_id)
     tx_counter: u16,
+    /// Liveness of the gateway/SSE connections, maintained by
+    /// `check_and_reconnect`.
+    status: ConnectionStatus,
+    /// Runtime the signer spawns its background tasks onto (the local
+    /// participant server, the connectivity checker, and a detached
+    /// shutdown task from `Drop`). Injected by the caller instead of `Drop`
+    /// spinning up its own `tokio::runtime::Runtime`, which would panic or
+    /// deadlock if the `Signer` is dropped from within an existing runtime.
+    executor: tokio::runtime::Handle,
 }
 
 impl Signer {
-    /// Create a new Signer instance with direct config (no file loading)
-    pub async fn new(config: SignerConfig) -> Result<Self> {
+    /// Create a new Signer instance with direct config (no file loading).
+    /// `executor` is the runtime this signer spawns its background tasks
+    /// onto; pass `Handle::current()` when called from within a runtime, or
+    /// a dedicated runtime's handle otherwise.
+    pub async fn new(config: SignerConfig, executor: tokio::runtime::Handle) -> Result<Self> {
         Self::setup_logging(&config.log_level)?;
 
         info!("Initializing MPC Signer...");
@@ -76,31 +179,111 @@ impl Signer {
         info!("Instance ID: 0x{:04X} (for tx_id generation)", instance_id);
 
         // Connect to remote services
-        let mut remote_clients = Vec::new();
-        
+        let mut remote_participants = Vec::new();
+
         let sign_service_uri = format!("http://{}:{}",
                                      config.sign_gateway_host,
                                      config.sign_gateway_port);
 
         info!("Connecting to sign-gateway at: {}", sign_service_uri);
-        let channel = Channel::from_shared(sign_service_uri)?
-            .connect()
+        let channel = Self::dial(sign_service_uri.clone(), config.tls.as_ref(), config.proxy.as_ref(), None)
             .await
             .context("Failed to connect to sign-gateway")?;
-        
-        remote_clients.push(ParticipantClient::new(channel));
+
+        remote_participants.push(ParticipantEntry {
+            uri: sign_service_uri,
+            client: ParticipantClient::new(channel),
+            failure_count: 0,
+        });
         info!("Connected to sign-gateway participant");
 
+        let participants = Arc::new(RwLock::new(remote_participants));
+        let metrics = Arc::new(SignerMetrics::new());
+        let check_interval = std::time::Duration::from_secs(config.connectivity_check_interval_secs);
+        let tls = config.tls.clone();
+        let proxy = config.proxy.clone();
+        let connectivity_checker_handle = executor.spawn(Self::run_connectivity_checker(
+            participants.clone(),
+            check_interval,
+            metrics.clone(),
+            tls,
+            proxy,
+        ));
+
         Ok(Self {
             config,
             local_participant_server: None,
             local_participant_handle: None,
-            all_participant_clients: remote_clients,
+            participants,
+            connectivity_checker_handle: Some(connectivity_checker_handle),
+            metrics,
+            metrics_server_handle: None,
             instance_id,
             tx_counter: 0,
+            status: ConnectionStatus::Connected,
+            executor,
         })
     }
 
+    /// Builds a tonic `Channel` to `uri`, securing it with `tls` (mutual TLS
+    /// if `tls` carries client certificate material) when present instead of
+    /// a plaintext connection, and applying `connect_timeout` when given.
+    /// Shared by every dial/redial site in this file so they all pick up TLS
+    /// the same way.
+    async fn dial(
+        uri: String,
+        tls: Option<&TlsConfig>,
+        proxy: Option<&ProxyConfig>,
+        connect_timeout: Option<std::time::Duration>,
+    ) -> Result<Channel> {
+        let mut endpoint = Channel::from_shared(uri)?;
+        if let Some(tls) = tls {
+            endpoint = endpoint.tls_config(tls.to_tonic_client_config()?)?;
+        }
+        if let Some(timeout) = connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        match proxy {
+            Some(proxy) => proxy.connect_channel(endpoint).await,
+            None => Ok(endpoint.connect().await?),
+        }
+    }
+
+    /// Periodically re-dials every known participant and, on success, swaps
+    /// in the freshly-connected channel; a dial failure is logged and the
+    /// existing (possibly already-dead) client is left in place for the next
+    /// tick to retry. Runs for the lifetime of the `Signer`.
+    async fn run_connectivity_checker(
+        participants: Arc<RwLock<Vec<ParticipantEntry>>>,
+        interval: std::time::Duration,
+        metrics: Arc<SignerMetrics>,
+        tls: Option<TlsConfig>,
+        proxy: Option<ProxyConfig>,
+    ) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            let uris: Vec<String> = participants.read().await.iter().map(|p| p.uri.clone()).collect();
+            for (i, uri) in uris.into_iter().enumerate() {
+                match Self::dial(uri.clone(), tls.as_ref(), proxy.as_ref(), Some(std::time::Duration::from_secs(3))).await {
+                    Ok(channel) => {
+                        let mut guard = participants.write().await;
+                        if let Some(entry) = guard.get_mut(i) {
+                            entry.client = ParticipantClient::new(channel);
+                        }
+                        metrics.record_reconnect();
+                    }
+                    Err(e) => {
+                        warn!("Connectivity check: participant {} at {} unreachable: {}", i, uri, e);
+                    }
+                }
+            }
+        }
+    }
+
     /// Generate a unique instance identifier for this Signer instance
     ///
     /// Combines timestamp (milliseconds) and random number to create a 16-bit ID
@@ -194,12 +377,19 @@ impl Signer {
 
         // Create participant server using new interface with pre-loaded key shares
         let sse_url = format!("http://{}:{}", self.config.sse_host, self.config.sse_port);
-        let participant_server = ParticipantServer::new(
+        let participant_server = ParticipantServer::new_with_transport(
             &sse_url,
             &self.config.local_participant_host,
             self.config.local_participant_port,
             key_shares,
-        ).map_err(|e| anyhow::anyhow!("Failed to create local participant server: {}", e))?;
+            participant::RequesterPolicy::empty(),
+            self.config.tls.as_ref(),
+            None,
+            None,
+            self.config.proxy.as_ref(),
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to create local participant server: {}", e))?
+        .with_total_participants(self.config.total_participants);
 
         info!("Local participant server created - {}:{}", 
               self.config.local_participant_host,
@@ -209,8 +399,10 @@ impl Signer {
         // Clone the participant server for the background task
         let participant_server_clone = participant_server.clone();
 
-        // Start participant server in background
-        let handle = tokio::spawn(async move {
+        // Start participant server in background, on the injected executor
+        // rather than `tokio::spawn` so it always lands on the runtime the
+        // caller chose, even if this method isn't itself running on it.
+        let handle = self.executor.spawn(async move {
             participant_server_clone.start().await
                 .map_err(|e| anyhow::anyhow!("Local participant server failed: {}", e))
         });
@@ -229,14 +421,28 @@ impl Signer {
                                self.config.local_participant_port);
         
         info!("Connecting to local participant at: {}", local_uri);
-        let local_channel = Channel::from_shared(local_uri)?
-            .connect()
+        let local_channel = Self::dial(local_uri.clone(), self.config.tls.as_ref(), self.config.proxy.as_ref(), None)
             .await
             .context("Failed to connect to local participant")?;
-        
-        let local_client = ParticipantClient::new(local_channel);
-        self.all_participant_clients.push(local_client);
-        info!("Connected to local participant, total participants: {}", self.all_participant_clients.len());
+
+        let mut participants = self.participants.write().await;
+        participants.push(ParticipantEntry {
+            uri: local_uri,
+            client: ParticipantClient::new(local_channel),
+            failure_count: 0,
+        });
+        info!("Connected to local participant, total participants: {}", participants.len());
+        drop(participants);
+
+        // Serve /metrics alongside the local participant, on the same
+        // injected executor as every other background task this `Signer`
+        // owns.
+        let metrics = self.metrics.clone();
+        let metrics_host = self.config.metrics_host.clone();
+        let metrics_port = self.config.metrics_port;
+        self.metrics_server_handle = Some(self.executor.spawn(async move {
+            metrics.serve(&metrics_host, metrics_port).await
+        }));
 
         Ok(())
     }
@@ -246,19 +452,52 @@ impl Signer {
     /// # Arguments
     /// * `data` - Raw bytes to be signed
     /// * `account_id` - Account ID to identify which key share to use
+    /// * `chain` - Which chain's signing/recovery convention to apply; see
+    ///   `crate::chain::chain_signer`
     ///
     /// # Returns
     /// * `SignatureResult` - Contains r, s, v components of the signature
-    pub async fn sign(&mut self, data: Vec<u8>, account_id: String) -> Result<SignatureResult> {
+    pub async fn sign(&mut self, data: Vec<u8>, account_id: String, chain: Chain) -> Result<SignatureResult> {
         info!("Starting MPC signature process...");
         info!("Data size: {} bytes", data.len());
         info!("Account ID: {}", account_id);
+        info!("Chain: {:?}", chain);
+
+        // Validate account_id exists in our key shares, and recover the
+        // account's shared public key so every response can be verified
+        // against it before being returned.
+        let key_share_data = self.config.key_shares.iter()
+            .find(|ks| ks.account_id == account_id)
+            .ok_or_else(|| anyhow::anyhow!("Account ID '{}' not found in available key shares", account_id))?;
+        let account_key_share: KeyShare<Secp256k1, SecurityLevel128> = serde_json::from_str(&key_share_data.key_share_data)
+            .map_err(|e| anyhow::anyhow!("Failed to parse key share for {}: {}", account_id, e))?;
+        let expected_public_key = VerifyingKey::from_sec1_bytes(&account_key_share.shared_public_key.to_bytes(false))
+            .map_err(|e| anyhow::anyhow!("Invalid shared public key for account {}: {}", account_id, e))?;
 
-        // Validate account_id exists in our key shares
-        if !self.config.key_shares.iter().any(|ks| ks.account_id == account_id) {
-            return Err(anyhow::anyhow!("Account ID '{}' not found in available key shares", account_id));
+        // If the last supervisor check found the link down, give it a bounded
+        // window to come back instead of failing this request immediately.
+        if !matches!(self.status, ConnectionStatus::Connected) {
+            let wait = tokio::time::timeout(std::time::Duration::from_secs(10), async {
+                loop {
+                    let _ = self.check_and_reconnect().await;
+                    if matches!(self.status, ConnectionStatus::Connected) {
+                        return;
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                }
+            })
+            .await;
+
+            if wait.is_err() {
+                log::warn!("Connectivity not restored after 10s wait; proceeding with sign anyway");
+            }
         }
 
+        // Starts the signing-round latency histogram sample; records the
+        // round's outcome (and bumps the active-round gauge back down) when
+        // dropped, whichever `return` path this call takes.
+        let round = RoundGuard::start(self.metrics.clone());
+
         // Generate unique tx_id using instance_id + counter
         let tx_id = self.next_tx_id();
 
@@ -266,93 +505,461 @@ impl Signer {
         let execution_id = Uuid::new_v4();
         info!("Starting signature request - TX ID: {}, Execution ID: {}", tx_id, execution_id);
 
+        // `data` is already the message hash the participants sign over
+        // (see `participant::signing::Signing::sign_tx`), so it doubles as
+        // the digest each response is verified against below.
+        let message_hash = data.clone();
+
         // Prepare sign request with account_id instead of derivation_path
         let sign_message = SignMessage {
             tx_id,
             execution_id: execution_id.as_bytes().to_vec(),
-            chain: Chain::Ethereum.into(),
+            chain: chain.into(),
             data,
             account_id,
         };
 
-        // Send sign requests to all participants (must reach threshold)
-        info!("Sending sign requests to {} participants...", self.all_participant_clients.len());
-        
-        let futures = self.all_participant_clients.iter_mut().take(self.config.threshold as usize).map(|client| {
-            let request = tonic::Request::new(sign_message.clone());
-            async move {
-                client.sign_tx(request).await
-            }
-        });
+        // Snapshot the freshest entries the connectivity checker has built so
+        // far, healthiest (fewest recent failures) first; cloning a
+        // `ParticipantClient<Channel>` just clones the underlying `Channel`
+        // handle, not a new connection.
+        let mut entries: Vec<ParticipantEntry> = self.participants.read().await.clone();
+        entries.sort_by_key(|p| p.failure_count);
+        let threshold = self.config.threshold as usize;
+
+        // Fan out to every available participant rather than exactly
+        // `threshold` of them, so one or two unhealthy nodes don't sink the
+        // round when spares are available; stop as soon as `threshold`
+        // successes have come back and cancel whatever's still in flight.
+        info!(
+            "Sending sign requests to {} participant(s), {} needed for quorum...",
+            entries.len(), threshold
+        );
 
-        let results = join_all(futures).await;
+        let mut join_set = tokio::task::JoinSet::new();
+        for mut entry in entries {
+            let request = tonic::Request::new(sign_message.clone());
+            join_set.spawn(async move {
+                let result = entry.client.sign_tx(request).await;
+                (entry.uri, result)
+            });
+        }
 
-        // Check signature results
         let mut successful_signatures = Vec::new();
-        for (i, result) in results.into_iter().enumerate() {
-            match result {
-                Ok(response) => {
+        let mut succeeded_uris = Vec::new();
+        let mut failed_uris = Vec::new();
+        while successful_signatures.len() < threshold {
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((uri, Ok(response))) => {
                     let signature = response.into_inner();
-                    info!("Received signature from participant {}: r_len={}, s_len={}, v={}", 
-                          i, signature.r.len(), signature.s.len(), signature.v);
-                    successful_signatures.push(SignatureResult {
-                        r: signature.r,
-                        s: signature.s,
-                        v: signature.v,
-                    });
+                    info!("Received signature from {}: r_len={}, s_len={}, v={}",
+                          uri, signature.r.len(), signature.s.len(), signature.v);
+
+                    if chain_signer(chain).verify(&expected_public_key, &message_hash, &signature.r, &signature.s, signature.v) {
+                        self.metrics.record_participant_result(&uri, true);
+                        successful_signatures.push(SignatureResult {
+                            r: signature.r,
+                            s: signature.s,
+                            v: signature.v,
+                            verified: true,
+                        });
+                        succeeded_uris.push(uri);
+                    } else {
+                        error!("Signature from {} failed verification against the account's public key; discarding", uri);
+                        self.metrics.record_participant_result(&uri, false);
+                        failed_uris.push(uri);
+                    }
+                }
+                Ok((uri, Err(e))) => {
+                    error!("Failed to get signature from {}: {}", uri, e);
+                    self.metrics.record_participant_result(&uri, false);
+                    self.metrics.record_grpc_error(&e);
+                    failed_uris.push(uri);
                 }
                 Err(e) => {
-                    error!("Failed to get signature from participant {}: {}", i, e);
+                    error!("Sign task panicked or was cancelled: {}", e);
+                }
+            }
+        }
+        // Quorum reached (or the pool ran out); anything still running can't
+        // change the outcome, so don't wait on it.
+        join_set.abort_all();
+
+        {
+            let mut participants = self.participants.write().await;
+            for uri in &failed_uris {
+                if let Some(p) = participants.iter_mut().find(|p| &p.uri == uri) {
+                    p.failure_count = p.failure_count.saturating_add(1);
+                }
+            }
+            for uri in &succeeded_uris {
+                if let Some(p) = participants.iter_mut().find(|p| &p.uri == uri) {
+                    p.failure_count = 0;
                 }
             }
         }
 
-        if successful_signatures.is_empty() {
-            return Err(anyhow::anyhow!("No valid signatures received"));
+        if successful_signatures.len() < threshold {
+            round.finish(false);
+            return Err(anyhow::anyhow!(
+                "Only {} of {} required signatures received before the participant pool was exhausted",
+                successful_signatures.len(), threshold
+            ));
         }
 
         // Return the first valid signature
         let signature = successful_signatures.into_iter().next().unwrap();
         info!("MPC signature completed successfully");
+        round.finish(true);
 
         Ok(signature)
     }
 
+    /// The currently active config, e.g. so a caller can carry over fields
+    /// (like `key_shares`) that `reload_config` won't accept changes to.
+    pub fn config(&self) -> &SignerConfig {
+        &self.config
+    }
+
+    /// Applies a new config in place without tearing down the running
+    /// signer, rejecting any change to a field that would invalidate the
+    /// live cryptographic state (threshold, participant count, local index,
+    /// or the key shares themselves). Mutable fields (remote host/port, SSE
+    /// endpoint, log level) are swapped atomically and logged old→new.
+    pub fn reload_config(&mut self, new: SignerConfig) -> std::result::Result<(), ConfigError> {
+        if new.local_participant_index != self.config.local_participant_index {
+            return Err(ConfigError::ImmutableField {
+                field: "local_participant_index".to_string(),
+                current: self.config.local_participant_index.to_string(),
+                requested: new.local_participant_index.to_string(),
+            });
+        }
+        if new.threshold != self.config.threshold {
+            return Err(ConfigError::ImmutableField {
+                field: "threshold".to_string(),
+                current: self.config.threshold.to_string(),
+                requested: new.threshold.to_string(),
+            });
+        }
+        if new.total_participants != self.config.total_participants {
+            return Err(ConfigError::ImmutableField {
+                field: "total_participants".to_string(),
+                current: self.config.total_participants.to_string(),
+                requested: new.total_participants.to_string(),
+            });
+        }
+        if new.key_shares != self.config.key_shares {
+            return Err(ConfigError::ImmutableField {
+                field: "key_shares".to_string(),
+                current: format!("{} share(s)", self.config.key_shares.len()),
+                requested: format!("{} share(s)", new.key_shares.len()),
+            });
+        }
+
+        macro_rules! apply {
+            ($field:ident) => {
+                if new.$field != self.config.$field {
+                    info!(
+                        "Config hot-reload: {} changed {:?} -> {:?}",
+                        stringify!($field),
+                        self.config.$field,
+                        new.$field
+                    );
+                    self.config.$field = new.$field.clone();
+                }
+            };
+        }
+
+        apply!(local_participant_host);
+        apply!(local_participant_port);
+        apply!(sign_gateway_host);
+        apply!(sign_gateway_port);
+        apply!(sse_host);
+        apply!(sse_port);
+        apply!(log_level);
+
+        Ok(())
+    }
+
+    /// Current liveness of the gateway/SSE link, as of the last
+    /// `check_and_reconnect` call.
+    pub fn connection_status(&self) -> ConnectionStatus {
+        self.status
+    }
+
+    /// Probes liveness of the gRPC link to the sign-gateway and, if a local
+    /// participant is running, its SSE subscription; on failure tears both
+    /// down and re-establishes them. Intended to be called periodically by a
+    /// supervisor task (see `mpc_client::MpcSigner::start_supervisor`).
+    pub async fn check_and_reconnect(&mut self) -> Result<()> {
+        let gateway_ok = self.probe_gateway().await;
+        let sse_ok = match &self.local_participant_server {
+            Some(_) => self.probe_sse().await,
+            None => true,
+        };
+
+        if gateway_ok && sse_ok {
+            if self.status != ConnectionStatus::Connected {
+                info!("Connectivity restored");
+            }
+            self.status = ConnectionStatus::Connected;
+            return Ok(());
+        }
+
+        let attempts = match self.status {
+            ConnectionStatus::Reconnecting { attempts } => attempts + 1,
+            _ => 1,
+        };
+
+        if attempts > MAX_RECONNECT_ATTEMPTS {
+            error!("Giving up reconnecting after {} attempts", attempts - 1);
+            self.status = ConnectionStatus::Disconnected;
+            return Ok(());
+        }
+
+        self.status = ConnectionStatus::Reconnecting { attempts };
+        log::warn!(
+            "Connectivity check failed (attempt {}): gateway_ok={}, sse_ok={}",
+            attempts, gateway_ok, sse_ok
+        );
+
+        if !gateway_ok {
+            if let Err(e) = self.reconnect_gateway().await {
+                error!("Failed to reconnect to sign-gateway: {}", e);
+            }
+        }
+        if !sse_ok {
+            if let Err(e) = self.restart_local_participant().await {
+                error!("Failed to restart local participant/SSE link: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the sign-gateway still accepts connections, by dialing it
+    /// fresh with a short timeout (the stored channel itself is left alone).
+    async fn probe_gateway(&self) -> bool {
+        let uri = format!("http://{}:{}", self.config.sign_gateway_host, self.config.sign_gateway_port);
+        Self::dial(uri, self.config.tls.as_ref(), self.config.proxy.as_ref(), Some(std::time::Duration::from_secs(3)))
+            .await
+            .is_ok()
+    }
+
+    /// Re-dials the sign-gateway and swaps in the fresh channel if it
+    /// succeeds.
+    async fn reconnect_gateway(&mut self) -> Result<()> {
+        let uri = format!("http://{}:{}", self.config.sign_gateway_host, self.config.sign_gateway_port);
+        info!("Reconnecting to sign-gateway at {}", uri);
+        let channel = Self::dial(uri.clone(), self.config.tls.as_ref(), self.config.proxy.as_ref(), Some(std::time::Duration::from_secs(3)))
+            .await
+            .context("Failed to reconnect to sign-gateway")?;
+
+        let entry = ParticipantEntry { uri, client: ParticipantClient::new(channel), failure_count: 0 };
+        let mut participants = self.participants.write().await;
+        if participants.is_empty() {
+            participants.push(entry);
+        } else {
+            participants[0] = entry;
+        }
+        drop(participants);
+        self.metrics.record_reconnect();
+        info!("Reconnected to sign-gateway");
+        Ok(())
+    }
+
+    /// Checks that the SSE endpoint backing the local participant is
+    /// reachable.
+    async fn probe_sse(&self) -> bool {
+        let url = format!("http://{}:{}", self.config.sse_host, self.config.sse_port);
+        let mut builder = match &self.config.tls {
+            Some(tls) => match tls.to_rustls_client_config() {
+                Ok(tls_config) => reqwest::Client::builder().use_preconfigured_tls(tls_config),
+                Err(e) => {
+                    error!("Failed to build TLS config for SSE probe: {}", e);
+                    return false;
+                }
+            },
+            None => reqwest::Client::builder(),
+        };
+        if let Some(proxy) = &self.config.proxy {
+            builder = match reqwest::Proxy::all(proxy.to_proxy_url()) {
+                Ok(proxy) => builder.proxy(proxy),
+                Err(e) => {
+                    error!("Failed to build SOCKS5 proxy config for SSE probe: {}", e);
+                    return false;
+                }
+            };
+        }
+
+        let Ok(client) = builder.timeout(std::time::Duration::from_secs(3)).build() else {
+            return false;
+        };
+        client.get(&url).send().await.is_ok()
+    }
+
+    /// Tears down and restarts the local participant server (and therefore
+    /// its SSE subscription) in place.
+    async fn restart_local_participant(&mut self) -> Result<()> {
+        info!("Restarting local participant server after SSE connectivity loss");
+        self.stop_local_participant().await?;
+        self.start_local_participant().await
+    }
+
     /// Stop local participant server gracefully
     pub async fn stop_local_participant(&mut self) -> Result<()> {
+        if let Some(handle) = self.metrics_server_handle.take() {
+            handle.abort();
+        }
+        Self::shutdown_local_participant(
+            self.local_participant_server.take(),
+            self.local_participant_handle.take(),
+        )
+        .await
+    }
+
+    /// Adds (or replaces) an account's key share on the running local
+    /// participant, making it available to the next `sign_tx` call without
+    /// restarting the signer; see
+    /// [`participant::ParticipantServer::add_key_share`]. Also updates
+    /// `config.key_shares` so a later `stop_local_participant` +
+    /// `start_local_participant` restart picks it up too. Errors if the
+    /// local participant hasn't been started yet.
+    pub async fn add_account_key_share(&mut self, key_share: KeyShareData) -> Result<()> {
+        let parsed: KeyShare<Secp256k1, SecurityLevel128> = serde_json::from_str(&key_share.key_share_data)
+            .map_err(|e| anyhow::anyhow!("Failed to parse key share for {}: {}", key_share.account_id, e))?;
+
+        let server = self
+            .local_participant_server
+            .as_ref()
+            .context("local participant server is not running; call start_local_participant first")?;
+        server.add_key_share(key_share.account_id.clone(), parsed).await;
+
+        self.config.key_shares.retain(|k| k.account_id != key_share.account_id);
+        self.config.key_shares.push(key_share);
+        Ok(())
+    }
+
+    /// Removes an account's key share from the running local participant,
+    /// returning whether it was present; also drops it from
+    /// `config.key_shares`. Errors if the local participant hasn't been
+    /// started yet.
+    pub async fn remove_account_key_share(&mut self, account_id: &str) -> Result<bool> {
+        let server = self
+            .local_participant_server
+            .as_ref()
+            .context("local participant server is not running; call start_local_participant first")?;
+        let removed = server.remove_key_share(account_id).await;
+
+        self.config.key_shares.retain(|k| k.account_id != account_id);
+        Ok(removed)
+    }
+
+    /// Lists the account ids the running local participant currently has key
+    /// shares for. Errors if the local participant hasn't been started yet.
+    pub async fn list_account_ids(&self) -> Result<Vec<String>> {
+        let server = self
+            .local_participant_server
+            .as_ref()
+            .context("local participant server is not running; call start_local_participant first")?;
+        Ok(server.account_ids().await)
+    }
+
+    /// Proactively re-randomizes `account_id`'s key share against a
+    /// suspected compromise, without changing its public key/address; see
+    /// [`participant::ParticipantServer::refresh_key_share`] for the
+    /// protocol and its invariants. Every other participant for this account
+    /// must call this at the same time with the same `execution_id`.
+    /// Persists the refreshed share to `output_path` (the same dictionary
+    /// format `load_mpc_config` reads) and updates `config.key_shares` so a
+    /// later restart picks it up too. Errors, leaving the old share and
+    /// `output_path` untouched, if the local participant hasn't been
+    /// started yet or if the refresh itself fails or disagrees on the
+    /// resulting public key.
+    pub async fn refresh_account_key_share(
+        &mut self,
+        account_id: &str,
+        execution_id: &[u8],
+        output_path: &str,
+    ) -> Result<()> {
+        let server = self
+            .local_participant_server
+            .as_ref()
+            .context("local participant server is not running; call start_local_participant first")?;
+        let refreshed = server.refresh_key_share(account_id, execution_id, output_path).await
+            .map_err(|e| anyhow::anyhow!("key refresh failed for account_id {}: {}", account_id, e))?;
+
+        let key_share_data = serde_json::to_string(&refreshed)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize refreshed key share for {}: {}", account_id, e))?;
+        self.config.key_shares.retain(|k| k.account_id != account_id);
+        self.config.key_shares.push(KeyShareData {
+            account_id: account_id.to_string(),
+            key_share_data,
+        });
+        Ok(())
+    }
+
+    /// Drives the actual shutdown steps against an owned `ParticipantServer`
+    /// and its task handle, rather than `&mut self`, so `Drop` can spawn this
+    /// as a detached task on `executor` without holding a borrow of `self`
+    /// across an `.await`.
+    async fn shutdown_local_participant(
+        participant_server: Option<ParticipantServer>,
+        handle: Option<JoinHandle<Result<()>>>,
+    ) -> Result<()> {
         info!("🛑 Initiating graceful shutdown of local participant server...");
 
-        // Step 1: Call shutdown on the ParticipantServer for graceful shutdown
-        if let Some(ref participant_server) = self.local_participant_server {
+        // Step 1: Register for the server's clean-exit signal before asking
+        // it to shut down, so there's no race between the signal firing and
+        // us starting to listen for it.
+        let completion_rx = match &participant_server {
+            Some(participant_server) => Some(participant_server.completion_receiver().await),
+            None => None,
+        };
+
+        // Step 2: Call shutdown on the ParticipantServer for graceful shutdown
+        if let Some(ref participant_server) = participant_server {
             info!("Calling ParticipantServer::shutdown()...");
             participant_server.shutdown().await
                 .map_err(|e| anyhow::anyhow!("Failed to shutdown participant server: {}", e))?;
             info!("✓ ParticipantServer shutdown completed");
         }
 
-        // Step 2: Wait for the server task to complete or abort it
-        if let Some(handle) = self.local_participant_handle.take() {
-            info!("Waiting for server task to complete...");
-
-            // Give the server a bit of time to finish gracefully
-            let timeout = tokio::time::Duration::from_secs(5);
-            match tokio::time::timeout(timeout, handle).await {
-                Ok(result) => {
+        // Step 3: Wait for the server to report a clean exit over its
+        // completion oneshot, forcing it by aborting the task if it doesn't
+        // show up within the timeout instead of silently giving up.
+        let timeout = tokio::time::Duration::from_secs(5);
+        if let Some(completion_rx) = completion_rx {
+            info!("Waiting for server to report a clean exit...");
+            tokio::select! {
+                result = completion_rx => {
                     match result {
-                        Ok(Ok(())) => info!("✓ Server task completed successfully"),
-                        Ok(Err(e)) => error!("Server task finished with error: {}", e),
-                        Err(e) => error!("Server task panicked: {:?}", e),
+                        Ok(()) => info!("✓ Server reported a clean exit"),
+                        Err(_) => error!("Server's completion sender was dropped without signalling a clean exit"),
                     }
                 }
-                Err(_) => {
-                    info!("Server task did not complete within timeout, this is expected");
-                    // Note: The handle is already dropped, no need to abort
+                _ = tokio::time::sleep(timeout) => {
+                    error!("Server did not report a clean exit within {:?}; forcing shutdown by aborting its task", timeout);
+                    if let Some(handle) = &handle {
+                        handle.abort();
+                    }
                 }
             }
         }
 
-        // Step 3: Clear the participant server reference
-        self.local_participant_server = None;
+        // Step 4: Join the task to surface a panic or return error, now that
+        // it has either exited on its own or been aborted above.
+        if let Some(handle) = handle {
+            match handle.await {
+                Ok(Ok(())) => info!("✓ Server task completed successfully"),
+                Ok(Err(e)) => error!("Server task finished with error: {}", e),
+                Err(e) if e.is_cancelled() => info!("Server task was aborted after a forced shutdown"),
+                Err(e) => error!("Server task panicked: {:?}", e),
+            }
+        }
 
         info!("✅ Local participant server stopped successfully");
         Ok(())
@@ -392,23 +999,28 @@ impl Signer {
 
 impl Drop for Signer {
     fn drop(&mut self) {
-        // Attempt graceful shutdown when Signer is dropped
-        log::warn!("⚠️ Signer being dropped, attempting graceful shutdown...");
-
-        // We can't await in Drop, so we use a blocking approach
+        // Attempt graceful shutdown when Signer is dropped. `Drop` can't
+        // `.await`, and spinning up a fresh `tokio::runtime::Runtime` here
+        // (as before) panics or deadlocks when the drop happens from inside
+        // an existing runtime, so fire a detached task on the injected
+        // `executor` instead and don't wait for it to finish.
         if self.local_participant_server.is_some() || self.local_participant_handle.is_some() {
-            // Create a runtime for blocking cleanup
-            if let Ok(rt) = tokio::runtime::Runtime::new() {
-                let _ = rt.block_on(async {
-                    let _ = self.stop_local_participant().await;
-                });
-            } else {
-                // Fallback to abort if we can't create runtime
-                log::error!("Failed to create runtime for graceful shutdown, aborting task");
-                if let Some(handle) = self.local_participant_handle.take() {
-                    handle.abort();
-                }
-            }
+            log::warn!("⚠️ Signer being dropped, spawning detached shutdown of local participant...");
+            let participant_server = self.local_participant_server.take();
+            let handle = self.local_participant_handle.take();
+            self.executor.spawn(async move {
+                let _ = Self::shutdown_local_participant(participant_server, handle).await;
+            });
+        }
+
+        // The connectivity checker and metrics server have no state to
+        // drain, so they can just be aborted outright rather than given a
+        // detached shutdown task.
+        if let Some(handle) = self.connectivity_checker_handle.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.metrics_server_handle.take() {
+            handle.abort();
         }
 
         log::info!("Signer dropped");
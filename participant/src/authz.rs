@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use alloy::primitives::{keccak256, Address};
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::Deserialize;
+
+use crate::signing::{recover_public_key, EthereumSignature};
+
+/// Per-account allowlist of requester addresses authorized to trigger a
+/// signature for that account.
+///
+/// Loaded once alongside the key shares (e.g. a sibling `requester_policy.json`
+/// next to the key-share file) and consulted on every inbound `sign_tx`
+/// request before the party ever enters the MPC protocol.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RequesterPolicy {
+    #[serde(flatten)]
+    allowlist: HashMap<String, Vec<Address>>,
+}
+
+impl RequesterPolicy {
+    /// Loads a policy file mapping `account_id` to the list of addresses
+    /// authorized to request signatures for that account.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read requester policy file {}", path.display()))?;
+        let policy: Self = serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse requester policy file {}", path.display()))?;
+        info!(
+            "Loaded requester policy for {} account(s) from {}",
+            policy.allowlist.len(),
+            path.display()
+        );
+        Ok(policy)
+    }
+
+    /// An empty policy that authorizes nobody; used when no policy file is
+    /// configured so requests are rejected rather than silently allowed.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Whether no accounts have an allowlist configured at all, meaning
+    /// authorization is effectively turned off (legacy/demo mode).
+    pub fn is_empty(&self) -> bool {
+        self.allowlist.is_empty()
+    }
+
+    fn is_authorized(&self, account_id: &str, requester: &Address) -> bool {
+        self.allowlist
+            .get(account_id)
+            .map(|allowed| allowed.contains(requester))
+            .unwrap_or(false)
+    }
+
+    /// Verifies that `signature` is a valid detached ECDSA signature over
+    /// `(tx_id, chain, account_id, message_digest, execution_id,
+    /// derivation_path)` from a requester address present in the allowlist
+    /// for `account_id`.
+    ///
+    /// Returns the recovered, authorized requester address on success.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_request(
+        &self,
+        tx_id: &str,
+        chain: i32,
+        account_id: &str,
+        message_digest: &[u8],
+        execution_id: &[u8],
+        derivation_path: &[u32],
+        signature: &EthereumSignature,
+    ) -> Result<Address> {
+        let digest = request_digest(tx_id, chain, account_id, message_digest, execution_id, derivation_path);
+
+        let requester_key = recover_public_key(digest.as_slice(), signature)
+            .context("failed to recover requester public key from detached signature")?;
+
+        let uncompressed = requester_key.to_encoded_point(false);
+        let requester_address = Address::from_slice(&keccak256(&uncompressed.as_bytes()[1..])[12..]);
+
+        if !self.is_authorized(account_id, &requester_address) {
+            warn!(
+                "Rejected signing request for account_id {} from unauthorized requester {}",
+                account_id, requester_address
+            );
+            anyhow::bail!(
+                "requester {} is not authorized to sign for account_id {}",
+                requester_address,
+                account_id
+            );
+        }
+
+        info!(
+            "Authorized signing request for account_id {} from requester {}",
+            account_id, requester_address
+        );
+        Ok(requester_address)
+    }
+}
+
+/// Canonical digest the requester must sign, binding the signature to the
+/// specific transaction, chain, account, message, execution and derivation
+/// path so it cannot be replayed against a different request — including a
+/// different chain or transaction id carrying the same payload.
+///
+/// Every variable-length field is length-prefixed (as a big-endian `u64`)
+/// before its bytes, so two requests whose variable-length fields differ in
+/// where one ends and the next begins (e.g. `tx_id="AB", account_id="C..."`
+/// vs. `tx_id="ABC", account_id="..."`) can never hash to the same digest —
+/// `chain` doesn't need one since it's already fixed-width.
+fn request_digest(
+    tx_id: &str,
+    chain: i32,
+    account_id: &str,
+    message_digest: &[u8],
+    execution_id: &[u8],
+    derivation_path: &[u32],
+) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(
+        8 + tx_id.len()
+            + 4
+            + 8
+            + account_id.len()
+            + 8
+            + message_digest.len()
+            + 8
+            + execution_id.len()
+            + 8
+            + derivation_path.len() * 4,
+    );
+    buf.extend_from_slice(&(tx_id.len() as u64).to_be_bytes());
+    buf.extend_from_slice(tx_id.as_bytes());
+    buf.extend_from_slice(&chain.to_be_bytes());
+    buf.extend_from_slice(&(account_id.len() as u64).to_be_bytes());
+    buf.extend_from_slice(account_id.as_bytes());
+    buf.extend_from_slice(&(message_digest.len() as u64).to_be_bytes());
+    buf.extend_from_slice(message_digest);
+    buf.extend_from_slice(&(execution_id.len() as u64).to_be_bytes());
+    buf.extend_from_slice(execution_id);
+    buf.extend_from_slice(&(derivation_path.len() as u64).to_be_bytes());
+    for index in derivation_path {
+        buf.extend_from_slice(&index.to_be_bytes());
+    }
+    *keccak256(&buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shifting_the_tx_id_account_id_boundary_changes_the_digest() {
+        let a = request_digest("AB", 1, "CDEF", b"msg", b"exec", &[0]);
+        let b = request_digest("ABC", 1, "DEF", b"msg", b"exec", &[0]);
+        assert_ne!(a, b, "length-prefixing must stop a shifted tx_id/account_id boundary from colliding");
+    }
+
+    #[test]
+    fn shifting_the_execution_id_derivation_path_boundary_changes_the_digest() {
+        let a = request_digest("tx", 1, "acct", b"msg", b"exec", &[0x6578u32]);
+        let b = request_digest("tx", 1, "acct", b"msg", b"exece", &[]);
+        assert_ne!(a, b, "length-prefixing must stop a shifted execution_id/derivation_path boundary from colliding");
+    }
+
+    #[test]
+    fn same_inputs_produce_the_same_digest() {
+        let a = request_digest("tx", 7, "acct", b"msg", b"exec", &[1, 2]);
+        let b = request_digest("tx", 7, "acct", b"msg", b"exec", &[1, 2]);
+        assert_eq!(a, b);
+    }
+}
@@ -1,14 +1,23 @@
 use tokio_util::compat::TokioAsyncReadCompatExt;
+use std::sync::Arc;
 use std::time::Duration;
 
+use alloy::primitives::{keccak256, Address};
+use alloy::signers::k256::ecdsa::{signature::hazmat::PrehashSigner, RecoveryId, Signature, SigningKey};
 use anyhow::{Context, Result};
+use bytes::{Bytes, BytesMut};
 use futures::{Sink, Stream, StreamExt, TryStreamExt};
 use log::{debug, error, info};
+use rand::Rng;
 use round_based::{Incoming, Outgoing};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use thiserror::Error;
+use tokio_util::codec::{Encoder, LengthDelimitedCodec};
 use tokio_util::io::StreamReader;
 
+use crate::proxy::ProxyConfig;
+use crate::tls::TlsConfig;
+
 #[allow(unused_imports)]
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 
@@ -53,32 +62,288 @@ pub enum TransportError {
 
     #[error("Connection to room '{room_id}' failed")]
     ConnectionFailed { room_id: String },
+
+    #[error("SSE stream reconnection exhausted after {attempts} attempts, {messages_lost} message(s) may have been missed")]
+    ReconnectExhausted { attempts: u32, messages_lost: u32 },
+
+    #[error("Room '{room_id}' was already registered by another party")]
+    RoomAlreadyRegistered { room_id: String },
+}
+
+/// How the resilient SSE consumer retries a dropped connection. Configurable
+/// so operators can tune reconnect behavior per deployment; the `Default`
+/// impl matches the values this was hardcoded to before it became config.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ReconnectPolicy {
+    /// When `false`, a dropped SSE connection is not retried at all;
+    /// `Room::subscribe` yields `TransportError::ReconnectExhausted`
+    /// immediately on the first error instead.
+    #[serde(default = "default_reconnect_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+}
+
+fn default_reconnect_enabled() -> bool {
+    true
+}
+
+fn default_base_delay_ms() -> u64 {
+    250
+}
+
+fn default_max_delay_ms() -> u64 {
+    30_000
+}
+
+fn default_max_attempts() -> u32 {
+    8
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: default_reconnect_enabled(),
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            max_attempts: default_max_attempts(),
+        }
+    }
+}
+
+/// Retries allowed before giving up: `0` if reconnecting is disabled, so the
+/// very first failure exhausts the budget.
+fn effective_max_attempts(policy: &ReconnectPolicy) -> u32 {
+    if policy.enabled { policy.max_attempts } else { 0 }
+}
+
+/// Exponential backoff for the `attempts`-th retry (1-indexed), capped at
+/// `policy.max_delay_ms` plus up to 25% jitter so many reconnecting
+/// participants don't hammer the relay in lockstep.
+fn backoff_for(policy: &ReconnectPolicy, attempts: u32) -> Duration {
+    let base = Duration::from_millis(policy.base_delay_ms);
+    let max = Duration::from_millis(policy.max_delay_ms);
+    let capped = base
+        .saturating_mul(1u32 << attempts.saturating_sub(1).min(16))
+        .min(max);
+    let jitter_ceiling = (capped.as_millis() as u64 / 4).max(1);
+    let jitter_ms = rand::rngs::OsRng.gen_range(0..=jitter_ceiling);
+    capped + Duration::from_millis(jitter_ms)
+}
+
+/// How outgoing protocol messages reach the relay's broadcast endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TransportMode {
+    /// One `POST /broadcast` per outgoing message, paying full HTTP request
+    /// overhead each time. The original behavior; kept as the default so
+    /// existing deployments that don't set `transport.mode` are unaffected.
+    #[default]
+    Post,
+    /// A single long-lived, length-delimited-framed chunked upload per
+    /// `Room::join_room` call (see `Room::streaming_sink`), avoiding
+    /// per-message connection setup for multi-round signing sessions with
+    /// many participants.
+    Stream,
+}
+
+/// A detached ECDSA signature (`r || s || v`), hex encoded for JSON
+/// transport - wire-compatible with `sse::authz::DetachedSignature`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DetachedSignature {
+    r: String,
+    s: String,
+    v: u8,
+}
+
+/// Wire-compatible with `sse::authz::SignedBroadcast`, the envelope a
+/// registered room's `RoomPolicy::authorize_broadcast` requires in place of
+/// a bare string.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SignedBroadcast {
+    sender: Address,
+    signature: DetachedSignature,
+    payload: String,
+}
+
+/// Wire-compatible with the body `sse`'s `POST /rooms/{room_id}/register`
+/// expects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RegisterRoomRequest {
+    members: Vec<Address>,
+    party_count: usize,
+    sender: Address,
+    signature: DetachedSignature,
+}
+
+/// Must byte-for-byte match `sse::authz::broadcast_digest`, or every
+/// envelope this client signs will fail `RoomPolicy::authorize_broadcast`
+/// on the relay. Duplicated here rather than depending on the `sse` crate
+/// from a client, the same way `participant::authz::request_digest`
+/// duplicates its own server-side counterpart's digest.
+fn broadcast_digest(room_id: &str, payload: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(room_id.len() + payload.len());
+    buf.extend_from_slice(room_id.as_bytes());
+    buf.extend_from_slice(payload);
+    *keccak256(&buf)
 }
 
-#[derive(Clone, Debug)]
+/// Must byte-for-byte match `sse::authz::registration_digest` - see
+/// [`broadcast_digest`].
+fn registration_digest(room_id: &str, members: &[Address], party_count: usize) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(8 + room_id.len() + 8 + members.len() * 20);
+    buf.extend_from_slice(&(room_id.len() as u64).to_be_bytes());
+    buf.extend_from_slice(room_id.as_bytes());
+    buf.extend_from_slice(&(party_count as u64).to_be_bytes());
+    for member in members {
+        buf.extend_from_slice(member.as_slice());
+    }
+    *keccak256(&buf)
+}
+
+fn address_of(identity: &SigningKey) -> Address {
+    let uncompressed = identity.verifying_key().to_encoded_point(false);
+    Address::from_slice(&keccak256(&uncompressed.as_bytes()[1..])[12..])
+}
+
+/// Signs `digest` with `identity`, returning the signer's address alongside
+/// the detached signature the relay's `recover_sender` expects.
+fn sign_digest(identity: &SigningKey, digest: [u8; 32]) -> Result<(Address, DetachedSignature), TransportError> {
+    let signature: Signature = identity
+        .sign_prehash(&digest)
+        .map_err(|e| TransportError::Http(format!("failed to sign room digest: {e}")))?;
+    let recovery_id = RecoveryId::trial_recovery_from_msg(identity.verifying_key(), &digest, &signature)
+        .map_err(|e| TransportError::Http(format!("failed to derive recovery id for room digest: {e}")))?;
+
+    let bytes = signature.to_bytes();
+    Ok((
+        address_of(identity),
+        DetachedSignature {
+            r: alloy::hex::encode(&bytes[..32]),
+            s: alloy::hex::encode(&bytes[32..]),
+            v: recovery_id.to_byte(),
+        },
+    ))
+}
+
+#[derive(Clone)]
 pub struct Client {
     client: reqwest::Client,
     base_url: String,
+    reconnect: ReconnectPolicy,
+    transport_mode: TransportMode,
+    /// Signs this client's room registrations and broadcasts so the relay's
+    /// `RoomPolicy`/`authz::authorize_registration` can tell a real party
+    /// apart from anyone who merely learned the room id; see
+    /// [`Self::with_identity`]. `None` keeps the legacy unsigned behavior,
+    /// which only an unrestricted (never-registered) room still accepts.
+    identity: Option<Arc<SigningKey>>,
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .field("reconnect", &self.reconnect)
+            .field("transport_mode", &self.transport_mode)
+            .field("identity", &self.identity.as_ref().map(|_| "<configured>"))
+            .finish()
+    }
 }
 
 impl Client {
     pub fn new(address: reqwest::Url) -> Result<Self> {
-        info!("Creating new client for address: {}", address);
+        Self::new_with_options(address, None, None)
+    }
+
+    /// Same as [`Client::new`], but secures the SSE/HTTP transport with the
+    /// rustls TLS configuration built from `tls` (mutual TLS if `tls` has a
+    /// client certificate configured).
+    pub fn new_with_tls(address: reqwest::Url, tls: &TlsConfig) -> Result<Self> {
+        Self::new_with_options(address, Some(tls), None)
+    }
+
+    /// Same as [`Client::new`], but routes the SSE/HTTP transport through a
+    /// SOCKS5 proxy (e.g. Tor) instead of dialing `address` directly; see
+    /// [`ProxyConfig`].
+    pub fn new_with_proxy(address: reqwest::Url, proxy: &ProxyConfig) -> Result<Self> {
+        Self::new_with_options(address, None, Some(proxy))
+    }
 
-        let client = reqwest::Client::builder()
+    /// General entry point behind [`Self::new`], [`Self::new_with_tls`] and
+    /// [`Self::new_with_proxy`].
+    pub(crate) fn new_with_options(
+        address: reqwest::Url,
+        tls: Option<&TlsConfig>,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<Self> {
+        info!(
+            "Creating new client for address: {} (tls={}, proxy={})",
+            address, tls.is_some(), proxy.is_some()
+        );
+
+        let mut builder = reqwest::Client::builder()
             .timeout(Duration::from_secs(60))  // 完全禁用超时，SSE 需要长连接
-            .tcp_keepalive(Duration::from_secs(60))  // tcp_keepalive 接受 Duration，不是 Option
-            .build()
-            .context("Failed to build HTTP client")?;
+            .tcp_keepalive(Duration::from_secs(60)); // tcp_keepalive 接受 Duration，不是 Option
+        if let Some(tls) = tls {
+            builder = builder.use_preconfigured_tls(tls.to_rustls_client_config()?);
+        }
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.to_proxy_url())?);
+        }
+        let client = builder.build().context("Failed to build HTTP client")?;
 
         Ok(Self {
             client,
             base_url: address.to_string(),
+            reconnect: ReconnectPolicy::default(),
+            transport_mode: TransportMode::default(),
+            identity: None,
         })
     }
 
+    /// Overrides the reconnect policy every `Room` created from this client
+    /// uses for its SSE subscription (see [`Room::subscribe`]).
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    /// Overrides how every `Room` created from this client sends outgoing
+    /// protocol messages (see [`TransportMode`]).
+    pub fn with_transport_mode(mut self, mode: TransportMode) -> Self {
+        self.transport_mode = mode;
+        self
+    }
+
+    /// Sets the key every `Room` created from this client signs its
+    /// registration request and broadcasts with (see [`Room::register`],
+    /// [`Room::broadcast`]). Leave unset to keep sending unsigned requests -
+    /// the only thing an unrestricted (never-registered) room still accepts.
+    pub fn with_identity(mut self, identity: SigningKey) -> Self {
+        self.identity = Some(Arc::new(identity));
+        self
+    }
+
+    /// This client's address, derived from its identity key, or `None` if
+    /// no identity is configured.
+    pub fn address(&self) -> Option<Address> {
+        self.identity.as_deref().map(address_of)
+    }
+
     pub fn room(&self, room: &str) -> Room {
-        Room::new(self.client.clone(), self.base_url.clone(), room.to_string())
+        Room::new(
+            self.client.clone(),
+            self.base_url.clone(),
+            room.to_string(),
+            self.reconnect,
+            self.transport_mode,
+            self.identity.clone(),
+        )
     }
 }
 
@@ -86,15 +351,33 @@ impl Client {
 pub struct Room {
     client: reqwest::Client,
     base_url: String,
+    /// Raw room id (no `rooms/` prefix), the exact string the relay's
+    /// `broadcast_digest`/`registration_digest` bind a signature to; `room`
+    /// below is the URL path segment built from it.
+    room_id: String,
     room: String,
+    reconnect: ReconnectPolicy,
+    transport_mode: TransportMode,
+    identity: Option<Arc<SigningKey>>,
 }
 
 impl Room {
-    pub fn new(client: reqwest::Client, base_url: String, room: String) -> Self {
+    pub fn new(
+        client: reqwest::Client,
+        base_url: String,
+        room: String,
+        reconnect: ReconnectPolicy,
+        transport_mode: TransportMode,
+        identity: Option<Arc<SigningKey>>,
+    ) -> Self {
         Room {
             client,
             base_url,
             room: format!("rooms/{}", room),
+            room_id: room,
+            reconnect,
+            transport_mode,
+            identity,
         }
     }
 
@@ -102,6 +385,101 @@ impl Room {
         format!("{}/{}/{}", self.base_url.trim_end_matches('/'), self.room, endpoint)
     }
 
+    /// This room's identity address, or `None` if no identity is configured
+    /// (see [`Client::with_identity`]).
+    pub fn address(&self) -> Option<Address> {
+        self.identity.as_deref().map(address_of)
+    }
+
+    /// Wraps `payload` into a signed [`SignedBroadcast`] envelope when this
+    /// room has an identity configured, matching what a registered room's
+    /// `RoomPolicy::authorize_broadcast` requires; otherwise returns
+    /// `payload` unchanged, the bare-string form only an unrestricted room
+    /// still accepts.
+    fn signed_payload(&self, payload: &str) -> Result<String, TransportError> {
+        match &self.identity {
+            None => Ok(payload.to_string()),
+            Some(identity) => {
+                let digest = broadcast_digest(&self.room_id, payload.as_bytes());
+                let (sender, signature) = sign_digest(identity, digest)?;
+                serde_json::to_string(&SignedBroadcast {
+                    sender,
+                    signature,
+                    payload: payload.to_string(),
+                })
+                .map_err(TransportError::from)
+            }
+        }
+    }
+
+    /// Registers this room with the relay before anyone joins it, binding it
+    /// to `members`/`party_count` so `broadcast`/`subscribe`/`issue_unique_idx`
+    /// reject anyone outside that set - see [`sse::Db::register_room`] (the
+    /// relay-side counterpart) and the module-level docs on why this must
+    /// run before the first `join_room`. Requires this room's client to have
+    /// been built with [`Client::with_identity`], since the registration
+    /// request itself must be signed by one of `members`.
+    pub async fn register(&self, members: Vec<Address>, party_count: usize) -> Result<(), TransportError> {
+        let identity = self.identity.as_ref().ok_or_else(|| {
+            TransportError::Http("cannot register a room without a configured identity".to_string())
+        })?;
+
+        let digest = registration_digest(&self.room_id, &members, party_count);
+        let (sender, signature) = sign_digest(identity, digest)?;
+
+        let endpoint = self.endpoint("register");
+        debug!("Registering room at endpoint: {}", endpoint);
+
+        let response = self
+            .client
+            .post(&endpoint)
+            .json(&RegisterRoomRequest {
+                members,
+                party_count,
+                sender,
+                signature,
+            })
+            .send()
+            .await
+            .map_err(|e| {
+                let err = TransportError::Http(format!("Failed to register room: {}", e));
+                error!("{}", err);
+                err
+            })?;
+
+        if response.status() == reqwest::StatusCode::CONFLICT {
+            return Err(TransportError::RoomAlreadyRegistered {
+                room_id: self.room_id.clone(),
+            });
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TransportError::Http(format!(
+                "Failed to register room: server returned {} ({})",
+                status, body
+            )));
+        }
+
+        debug!("Room registration successful");
+        Ok(())
+    }
+
+    /// Same as [`Self::register`], but treats [`TransportError::RoomAlreadyRegistered`]
+    /// as success - the expected outcome when another party in the same
+    /// session wins the race to register this room first.
+    pub async fn register_or_join(&self, members: Vec<Address>, party_count: usize) -> Result<(), TransportError> {
+        match self.register(members, party_count).await {
+            Ok(()) => Ok(()),
+            Err(TransportError::RoomAlreadyRegistered { room_id }) => {
+                debug!("Room '{}' already registered by another party, joining as-is", room_id);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
     #[allow(dead_code)]
     async fn issue_index(&self) -> Result<u16, TransportError> {
         let endpoint = self.endpoint("issue_unique_idx");
@@ -135,9 +513,11 @@ impl Room {
         let endpoint = self.endpoint("broadcast");
         debug!("Broadcasting message to endpoint: {}", endpoint);
 
+        let body = self.signed_payload(message)?;
+
         self.client
             .post(&endpoint)
-            .body(message.to_string())
+            .body(body)
             .send()
             .await
             .map_err(|e| {
@@ -150,68 +530,163 @@ impl Room {
         Ok(())
     }
 
-    async fn subscribe(
-        &self,
-    ) -> Result<
-        std::pin::Pin<Box<dyn Stream<Item = Result<String, anyhow::Error>> + Send>>,
-        TransportError,
-    > {
-        let endpoint = self.endpoint("subscribe");
-        debug!("Subscribing to SSE stream at endpoint: {}", endpoint);
-
-        let response = self.client
-            .get(&endpoint)
-            .header("Accept", "text/event-stream")  // 明确接受 SSE
-            .header("Cache-Control", "no-cache")     // 禁用缓存
-            .header("Connection", "keep-alive")      // 保持连接
-            .send()
-            .await
-            .map_err(|e| {
-                let err = TransportError::Http(format!("Failed to subscribe to stream: {}", e));
-                error!("{}", err);
-                err
-            })?;
-
-        // Convert the response body into a byte stream
-        let byte_stream = response.bytes_stream();
-
-        // Convert Stream<Item = Result<Bytes, Error>> to AsyncRead
-        let byte_stream_mapped = byte_stream.map_err(|e| {
-            std::io::Error::new(std::io::ErrorKind::Other, e)
+    /// Opens the single long-lived chunked upload a [`TransportMode::Stream`]
+    /// room's outgoing sink writes every message onto, framed with a 4-byte
+    /// length prefix so the relay's `broadcast_stream` endpoint can split the
+    /// body back into individual messages. The request runs in the
+    /// background for the lifetime of the returned sender; dropping the
+    /// sender ends the upload.
+    fn open_broadcast_stream(&self) -> tokio::sync::mpsc::Sender<Bytes> {
+        let (frame_tx, mut frame_rx) = tokio::sync::mpsc::channel::<Bytes>(64);
+        let endpoint = self.endpoint("broadcast_stream");
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            let body_stream = async_stream::stream! {
+                while let Some(frame) = frame_rx.recv().await {
+                    yield Ok::<_, std::io::Error>(frame);
+                }
+            };
+
+            if let Err(e) = client
+                .post(&endpoint)
+                .body(reqwest::Body::wrap_stream(body_stream))
+                .send()
+                .await
+            {
+                error!("Streaming broadcast upload to {} failed: {}", endpoint, e);
+            }
         });
 
-        let stream_reader = StreamReader::new(byte_stream_mapped);
-        let async_read = stream_reader.compat();
-
-        // Use async-sse to decode SSE events
-        let events = async_sse::decode(async_read);
+        frame_tx
+    }
 
-        let stream = events.filter_map(|msg| {
-            Box::pin(async {
-                match msg {
-                    Ok(async_sse::Event::Message(msg)) => {
-                        Some(
-                            String::from_utf8(msg.into_bytes())
-                                .context("Received invalid UTF-8 in SSE message")
-                        )
+    /// Decodes one SSE connection's response body into `(event_id, data)`
+    /// pairs, buffering the lines of the frame currently being assembled in
+    /// `pending_data` until its terminating blank line arrives.
+    ///
+    /// Written by hand instead of reusing `async_sse::decode` because the
+    /// latter doesn't expose the `id:` field we need to resume a dropped
+    /// connection with `Last-Event-ID`.
+    fn decode_frames(
+        response: reqwest::Response,
+    ) -> impl Stream<Item = Result<(Option<u64>, String), TransportError>> {
+        let byte_stream = response
+            .bytes_stream()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e));
+        let mut lines = tokio::io::BufReader::new(StreamReader::new(byte_stream)).lines();
+
+        async_stream::stream! {
+            let mut pending_id: Option<u64> = None;
+            let mut pending_data: std::collections::VecDeque<String> = std::collections::VecDeque::new();
+
+            loop {
+                match lines.next_line().await {
+                    Ok(Some(line)) => {
+                        if line.is_empty() {
+                            if !pending_data.is_empty() {
+                                let data = Vec::from(pending_data.clone()).join("\n");
+                                pending_data.clear();
+                                yield Ok((pending_id.take(), data));
+                            }
+                        } else if let Some(id_str) = line.strip_prefix("id:") {
+                            pending_id = id_str.trim().parse().ok();
+                        } else if let Some(data_str) = line.strip_prefix("data:") {
+                            pending_data.push_back(data_str.trim_start().to_string());
+                        }
+                        // ignore event:/retry:/comment lines, nothing here needs them
                     }
-                    Ok(_) => {
-                        // ignore other types of SSE events (like comments, etc.)
-                        None
+                    Ok(None) => return,
+                    Err(e) => {
+                        yield Err(TransportError::Sse(format!("SSE stream error: {}", e)));
+                        return;
                     }
+                }
+            }
+        }
+    }
+
+    /// Subscribes to this room's SSE stream, reconnecting with exponential
+    /// backoff on a dropped connection and replaying missed frames via
+    /// `Last-Event-ID` so in-flight signing rounds don't lose messages.
+    /// Yields `(event_id, data)` pairs so callers can surface the relay's
+    /// event id for downstream dedup. Gives up and yields a
+    /// `TransportError::ReconnectExhausted` once the configured retry
+    /// budget runs out, or immediately on the first error if
+    /// `self.reconnect.enabled` is false.
+    fn subscribe(
+        &self,
+    ) -> std::pin::Pin<Box<dyn Stream<Item = Result<(u64, String), anyhow::Error>> + Send>> {
+        let room = self.clone();
+        let policy = self.reconnect;
+        let max_attempts = effective_max_attempts(&policy);
+
+        let stream = async_stream::stream! {
+            let mut last_event_id: Option<u64> = None;
+            let mut attempts: u32 = 0;
+            let mut messages_lost: u32 = 0;
+
+            loop {
+                let endpoint = room.endpoint("subscribe");
+                debug!("Subscribing to SSE stream at endpoint: {}", endpoint);
+
+                let mut request = room.client
+                    .get(&endpoint)
+                    .header("Accept", "text/event-stream")
+                    .header("Cache-Control", "no-cache")
+                    .header("Connection", "keep-alive");
+                if let Some(id) = last_event_id {
+                    request = request.header("Last-Event-ID", id.to_string());
+                }
+
+                let response = match request.send().await {
+                    Ok(response) => response,
                     Err(e) => {
-                        let err = anyhow::Error::new(TransportError::Sse(format!(
-                            "SSE stream error: {}",
-                            e
-                        )));
-                        error!("SSE stream error: {}", err);
-                        Some(Err(err))
+                        error!("Failed to subscribe to SSE stream: {}", e);
+                        attempts += 1;
+                        if attempts > max_attempts {
+                            yield Err(anyhow::Error::new(TransportError::ReconnectExhausted { attempts, messages_lost }));
+                            return;
+                        }
+                        tokio::time::sleep(backoff_for(&policy, attempts)).await;
+                        continue;
+                    }
+                };
+
+                attempts = 0;
+                let mut frames = Box::pin(Self::decode_frames(response));
+                let mut connection_broke = false;
+
+                while let Some(frame) = frames.next().await {
+                    match frame {
+                        Ok((id, data)) => {
+                            let event_id = id.unwrap_or_else(|| last_event_id.unwrap_or(0));
+                            last_event_id = Some(event_id);
+                            yield Ok((event_id, data));
+                        }
+                        Err(e) => {
+                            error!("SSE connection broke: {}, reconnecting from Last-Event-ID {:?}", e, last_event_id);
+                            messages_lost += 1;
+                            connection_broke = true;
+                            break;
+                        }
                     }
                 }
-            })
-        });
 
-        Ok(Box::pin(stream))
+                if !connection_broke {
+                    debug!("SSE stream ended cleanly, reconnecting");
+                }
+
+                attempts += 1;
+                if attempts > max_attempts {
+                    yield Err(anyhow::Error::new(TransportError::ReconnectExhausted { attempts, messages_lost }));
+                    return;
+                }
+                tokio::time::sleep(backoff_for(&policy, attempts)).await;
+            }
+        };
+
+        Box::pin(stream)
     }
 
     pub async fn join_room<M>(
@@ -234,16 +709,17 @@ impl Room {
         // Construct channel of incoming messages
         let incoming = self
             .subscribe()
-            .await?
             .map_err(TransportError::Network)
-            .and_then(|msg| {
+            .and_then(|(event_id, data)| {
                 Box::pin(async move {
-                    serde_json::from_str::<Msg<M>>(&msg).map_err(TransportError::from)
+                    serde_json::from_str::<Msg<M>>(&data)
+                        .map(|msg| (event_id, msg))
+                        .map_err(TransportError::from)
                 })
             });
 
         // Ignore incoming messages addressed to someone else
-        let incoming = incoming.try_filter(move |msg| {
+        let incoming = incoming.try_filter(move |(_, msg)| {
             let should_receive =
                 msg.sender != index && (msg.receiver.is_none() || msg.receiver == Some(index));
             if !should_receive {
@@ -255,9 +731,11 @@ impl Room {
             futures::future::ready(should_receive)
         });
 
-        // Convert Msg<M> to Incoming<M>
-        let incoming = incoming.map_ok(|msg| Incoming {
-            id: 0,
+        // Convert (event_id, Msg<M>) to Incoming<M>, using the relay's event
+        // id so downstream dedup sees a real, monotonically increasing id
+        // instead of a constant placeholder.
+        let incoming = incoming.map_ok(|(event_id, msg)| Incoming {
+            id: event_id,
             sender: msg.sender,
             msg_type: if msg.receiver.is_none() {
                 round_based::MessageType::Broadcast
@@ -270,30 +748,69 @@ impl Room {
         // Pin the incoming stream
         let incoming = Box::pin(incoming);
 
-        // Construct channel of outgoing messages
-        let outgoing =
-            futures::sink::unfold(outgoing_client, move |client, message: Outgoing<M>| {
-                let room = outgoing_room.clone();
-                Box::pin(async move {
-                    let msg = Msg {
-                        sender: index,
-                        receiver: match message.recipient {
-                            round_based::MessageDestination::AllParties => None,
-                            round_based::MessageDestination::OneParty(party_id) => Some(party_id),
+        // Construct channel of outgoing messages, either one POST per
+        // message or a single long-lived framed upload depending on
+        // `self.transport_mode`.
+        let outgoing: std::pin::Pin<Box<dyn Sink<Outgoing<M>, Error = TransportError> + Send>> =
+            match self.transport_mode {
+                TransportMode::Post => Box::pin(futures::sink::unfold(
+                    outgoing_client,
+                    move |client, message: Outgoing<M>| {
+                        let room = outgoing_room.clone();
+                        Box::pin(async move {
+                            let msg = Msg {
+                                sender: index,
+                                receiver: match message.recipient {
+                                    round_based::MessageDestination::AllParties => None,
+                                    round_based::MessageDestination::OneParty(party_id) => Some(party_id),
+                                },
+                                body: message.msg,
+                            };
+                            let serialized = serde_json::to_string(&msg).map_err(TransportError::from)?;
+                            room.broadcast(&serialized).await.map_err(|e| {
+                                error!("Failed to broadcast outgoing message: {}", e);
+                                e
+                            })?;
+                            Ok::<_, TransportError>(client)
+                        })
+                    },
+                )),
+                TransportMode::Stream => {
+                    let frame_tx = self.open_broadcast_stream();
+                    let mut codec = LengthDelimitedCodec::new();
+                    let signing_room = self.clone();
+                    Box::pin(futures::sink::unfold(
+                        frame_tx,
+                        move |frame_tx, message: Outgoing<M>| {
+                            let mut codec = codec.clone();
+                            let signing_room = signing_room.clone();
+                            Box::pin(async move {
+                                let msg = Msg {
+                                    sender: index,
+                                    receiver: match message.recipient {
+                                        round_based::MessageDestination::AllParties => None,
+                                        round_based::MessageDestination::OneParty(party_id) => Some(party_id),
+                                    },
+                                    body: message.msg,
+                                };
+                                let serialized = serde_json::to_string(&msg).map_err(TransportError::from)?;
+                                let body = signing_room.signed_payload(&serialized)?;
+
+                                let mut framed = BytesMut::new();
+                                codec
+                                    .encode(Bytes::from(body), &mut framed)
+                                    .map_err(|e| TransportError::Http(format!("Failed to frame outgoing message: {}", e)))?;
+
+                                frame_tx.send(framed.freeze()).await.map_err(|_| {
+                                    error!("Streaming broadcast channel closed");
+                                    TransportError::Broadcast
+                                })?;
+                                Ok::<_, TransportError>(frame_tx)
+                            })
                         },
-                        body: message.msg,
-                    };
-                    let serialized = serde_json::to_string(&msg).map_err(TransportError::from)?;
-                    room.broadcast(&serialized).await.map_err(|e| {
-                        error!("Failed to broadcast outgoing message: {}", e);
-                        e
-                    })?;
-                    Ok::<_, TransportError>(client)
-                })
-            });
-
-        // Pin the outgoing sink
-        let outgoing = Box::pin(outgoing);
+                    ))
+                }
+            };
 
         info!("Successfully joined room '{room}' with index {index}");
 
@@ -0,0 +1,244 @@
+//! Cosmos-SDK/Oasis-style address and signature conventions for accounts on
+//! the same secp256k1 key shares everything else in this crate signs with.
+//!
+//! There is no `Chain::Cosmos` to branch on here: `Chain` comes from the
+//! external `proto` crate (see [`crate::recover_address`]'s Bitcoin arm for
+//! the same constraint) and isn't something this tree can add a variant to.
+//! These are free functions a caller assembling a Cosmos-SDK transaction
+//! calls directly around a `Signing::sign_tx` (or equivalent) call, the same
+//! way `mpc_client::ethereum` assembles Ethereum's format around it.
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+
+/// The SHA-256 sighash Cosmos-SDK (and Oasis) transactions are signed over,
+/// unlike Ethereum/Bitcoin's keccak256/double-SHA256 conventions.
+pub fn cosmos_sighash(message: &[u8]) -> [u8; 32] {
+    Sha256::digest(message).into()
+}
+
+/// Serializes `(r, s)` as the 64-byte compact `r ‖ s` form Cosmos-SDK
+/// signatures use - no recovery id, no DER, just the two 32-byte scalars.
+/// Assumes `s` is already low-S normalized, which is mandatory here (unlike
+/// Ethereum, Cosmos-SDK signature verification rejects a high-S signature
+/// outright) and which `Signing::sign_tx` now guarantees for every chain.
+pub fn to_cosmos_signature(r: &[u8; 32], s: &[u8; 32]) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    out[..32].copy_from_slice(r);
+    out[32..].copy_from_slice(s);
+    out
+}
+
+/// Derives a Cosmos-SDK/Oasis-style account address from a *compressed*
+/// secp256k1 public key: `Bech32(hrp, RIPEMD160(SHA256(compressed_pubkey)))`.
+/// `hrp` is the chain-specific human-readable prefix (`"cosmos"`, `"oasis"`,
+/// a rollapp's own prefix, ...).
+pub fn cosmos_address(compressed_pubkey: &[u8], hrp: &str) -> Result<String> {
+    let sha = Sha256::digest(compressed_pubkey);
+    let hash = ripemd160(&sha);
+    Ok(bech32::encode(hrp, &hash))
+}
+
+/// Minimal RIPEMD-160 implementation - like [`crate::signing::to_bitcoin_der`]'s
+/// hand-rolled DER encoder, this avoids pulling in a dedicated crate for one
+/// algorithm this module needs nowhere else.
+fn ripemd160(input: &[u8]) -> [u8; 20] {
+    const R1: [u32; 80] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9,
+        5, 2, 14, 11, 8, 3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, 1, 9, 11, 10, 0, 8,
+        12, 4, 13, 3, 7, 15, 14, 5, 6, 2, 4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+    ];
+    const R2: [u32; 80] = [
+        5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, 6, 11, 3, 7, 0, 13, 5, 10, 14, 15,
+        8, 12, 4, 9, 1, 2, 15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, 8, 6, 4, 1, 3,
+        11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, 12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9,
+        11,
+    ];
+    const S1: [u32; 80] = [
+        11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, 7, 6, 8, 13, 11, 9, 7, 15, 7, 12,
+        15, 9, 11, 7, 13, 12, 11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, 11, 12, 14,
+        15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, 9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11,
+        8, 5, 6,
+    ];
+    const S2: [u32; 80] = [
+        8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, 9, 13, 15, 7, 12, 8, 9, 11, 7, 7,
+        12, 7, 6, 15, 13, 11, 9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, 15, 5, 8,
+        11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, 8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15,
+        13, 11, 11,
+    ];
+    const K1: [u32; 5] = [0x00000000, 0x5A827999, 0x6ED9EBA1, 0x8F1BBCDC, 0xA953FD4E];
+    const K2: [u32; 5] = [0x50A28BE6, 0x5C4DD124, 0x6D703EF3, 0x7A6D76E9, 0x00000000];
+
+    fn f(j: usize, x: u32, y: u32, z: u32) -> u32 {
+        match j / 16 {
+            0 => x ^ y ^ z,
+            1 => (x & y) | (!x & z),
+            2 => (x | !y) ^ z,
+            3 => (x & z) | (y & !z),
+            _ => x ^ (y | !z),
+        }
+    }
+
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+    let bit_len = (input.len() as u64) * 8;
+    let mut padded = input.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&bit_len.to_le_bytes());
+
+    for block in padded.chunks(64) {
+        let mut x = [0u32; 16];
+        for (i, word) in block.chunks(4).enumerate() {
+            x[i] = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+        }
+
+        let (mut a1, mut b1, mut c1, mut d1, mut e1) = (h[0], h[1], h[2], h[3], h[4]);
+        let (mut a2, mut b2, mut c2, mut d2, mut e2) = (h[0], h[1], h[2], h[3], h[4]);
+
+        for j in 0..80 {
+            let t1 = a1
+                .wrapping_add(f(j, b1, c1, d1))
+                .wrapping_add(x[R1[j] as usize])
+                .wrapping_add(K1[j / 16])
+                .rotate_left(S1[j])
+                .wrapping_add(e1);
+            a1 = e1;
+            e1 = d1;
+            d1 = c1.rotate_left(10);
+            c1 = b1;
+            b1 = t1;
+
+            let t2 = a2
+                .wrapping_add(f(79 - j, b2, c2, d2))
+                .wrapping_add(x[R2[j] as usize])
+                .wrapping_add(K2[j / 16])
+                .rotate_left(S2[j])
+                .wrapping_add(e2);
+            a2 = e2;
+            e2 = d2;
+            d2 = c2.rotate_left(10);
+            c2 = b2;
+            b2 = t2;
+        }
+
+        let t = h[1].wrapping_add(c1).wrapping_add(d2);
+        h[1] = h[2].wrapping_add(d1).wrapping_add(e2);
+        h[2] = h[3].wrapping_add(e1).wrapping_add(a2);
+        h[3] = h[4].wrapping_add(a1).wrapping_add(b2);
+        h[4] = h[0].wrapping_add(b1).wrapping_add(c2);
+        h[0] = t;
+    }
+
+    let mut out = [0u8; 20];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
+
+/// A from-scratch Bech32 (BIP-173) encoder - just enough to turn a 20-byte
+/// hash plus an HRP into the address string [`cosmos_address`] needs,
+/// without depending on an unconfirmed `bech32` crate in this snapshot.
+mod bech32 {
+    const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7";
+
+    fn polymod(values: &[u8]) -> u32 {
+        const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+        let mut chk: u32 = 1;
+        for &v in values {
+            let b = (chk >> 25) as u8;
+            chk = (chk & 0x1ffffff) << 5 ^ u32::from(v);
+            for (i, gen) in GEN.iter().enumerate() {
+                if (b >> i) & 1 != 0 {
+                    chk ^= gen;
+                }
+            }
+        }
+        chk
+    }
+
+    fn hrp_expand(hrp: &str) -> Vec<u8> {
+        let mut out: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+        out.push(0);
+        out.extend(hrp.bytes().map(|b| b & 31));
+        out
+    }
+
+    fn create_checksum(hrp: &str, data: &[u8]) -> [u8; 6] {
+        let mut values = hrp_expand(hrp);
+        values.extend_from_slice(data);
+        values.extend_from_slice(&[0u8; 6]);
+        let polymod = polymod(&values) ^ 1;
+        let mut checksum = [0u8; 6];
+        for (i, byte) in checksum.iter_mut().enumerate() {
+            *byte = ((polymod >> (5 * (5 - i))) & 31) as u8;
+        }
+        checksum
+    }
+
+    /// Regroups `data` (8-bit bytes) into 5-bit groups, the form Bech32 data
+    /// characters encode.
+    fn convert_bits(data: &[u8]) -> Vec<u8> {
+        let mut acc: u32 = 0;
+        let mut bits: u32 = 0;
+        let mut out = Vec::new();
+        for &byte in data {
+            acc = (acc << 8) | u32::from(byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out.push(((acc >> bits) & 31) as u8);
+            }
+        }
+        if bits > 0 {
+            out.push(((acc << (5 - bits)) & 31) as u8);
+        }
+        out
+    }
+
+    pub fn encode(hrp: &str, data: &[u8]) -> String {
+        let data5 = convert_bits(data);
+        let checksum = create_checksum(hrp, &data5);
+        let mut out = String::with_capacity(hrp.len() + 1 + data5.len() + checksum.len());
+        out.push_str(hrp);
+        out.push('1');
+        for &b in data5.iter().chain(checksum.iter()) {
+            out.push(CHARSET[b as usize] as char);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// RIPEMD-160("abc") known-answer test, from the algorithm's reference
+    /// test vectors - catches a transposed round constant or message
+    /// schedule index silently producing a wrong-but-plausible digest.
+    #[test]
+    fn ripemd160_matches_known_answer_test_vector() {
+        let digest = ripemd160(b"abc");
+        assert_eq!(hex::encode(digest), "8eb208f7e05d987a9b044a8e98c6b087f15a0bf");
+    }
+
+    /// BIP-173 test vector: the empty-payload Bech32 string for HRP `"a"`.
+    #[test]
+    fn bech32_matches_bip173_empty_payload_test_vector() {
+        assert_eq!(bech32::encode("a", &[]), "a12uel5l");
+    }
+
+    /// Exercises the full `cosmos_address` pipeline (SHA-256 -> RIPEMD-160 ->
+    /// Bech32) end to end so a regression in either primitive or in how
+    /// they're wired together shows up here, not just in their isolated KATs.
+    #[test]
+    fn cosmos_address_is_deterministic_and_well_formed() {
+        let pubkey = [0x02; 33];
+        let address = cosmos_address(&pubkey, "cosmos").unwrap();
+        assert!(address.starts_with("cosmos1"));
+        assert_eq!(address, cosmos_address(&pubkey, "cosmos").unwrap());
+    }
+}
@@ -1,6 +1,8 @@
 use cggmp21::signing::Signature;
 use generic_ec::{Curve, Point};
 
+use alloy::primitives::{keccak256, Address};
+use alloy::signers::k256::ecdsa::{RecoveryId, Signature as K256Signature, VerifyingKey};
 use anyhow::Context;
 use cggmp21::supported_curves::{Secp256k1};
 
@@ -12,6 +14,15 @@ pub trait ExternalVerifier<E: Curve> {
     ) -> anyhow::Result<()>;
 }
 
+/// Derives the Ethereum checksum-less address for a secp256k1 public key:
+/// the last 20 bytes of the keccak256 hash of its uncompressed encoding
+/// (with the leading `0x04` prefix byte stripped).
+pub fn public_key_to_eth_address(public_key: &Point<Secp256k1>) -> Address {
+    let uncompressed = public_key.to_bytes(false);
+    let hash = keccak256(&uncompressed.as_bytes()[1..]);
+    Address::from_slice(&hash[12..])
+}
+
 pub struct Bitcoin;
 
 impl ExternalVerifier<Secp256k1> for Bitcoin {
@@ -34,4 +45,43 @@ impl ExternalVerifier<Secp256k1> for Bitcoin {
             .verify(&message, &public_key)
             .context("invalid siganture")
     }
+}
+
+pub struct Ethereum;
+
+impl ExternalVerifier<Secp256k1> for Ethereum {
+    fn verify(
+        public_key: &generic_ec::Point<Secp256k1>,
+        signature: &cggmp21::signing::Signature<Secp256k1>,
+        message: &[u8],
+    ) -> anyhow::Result<()> {
+        let expected_address = public_key_to_eth_address(public_key);
+
+        let mut signature_bytes = [0u8; 64];
+        signature.write_to_slice(&mut signature_bytes);
+        let k256_sig = K256Signature::from_slice(&signature_bytes).context("malformed signature")?;
+
+        // `message` is the 32-byte prehash this was signed over, not a raw
+        // message to hash ourselves - the chain's own domain hashing (e.g.
+        // EIP-191/712) already happened before the MPC signing round, the
+        // same convention `participant::signing::recover_address` assumes.
+        for recovery_byte in [0u8, 1u8] {
+            let recovery_id = RecoveryId::try_from(recovery_byte).context("invalid recovery id")?;
+            let Ok(recovered) = VerifyingKey::recover_from_prehash(message, &k256_sig, recovery_id) else {
+                continue;
+            };
+            let recovered_address = {
+                let uncompressed = recovered.to_encoded_point(false);
+                let hash = keccak256(&uncompressed.as_bytes()[1..]);
+                Address::from_slice(&hash[12..])
+            };
+            if recovered_address == expected_address {
+                return Ok(());
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "signature does not recover to the expected public key's Ethereum address for either recovery id"
+        ))
+    }
 }
\ No newline at end of file
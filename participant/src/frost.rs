@@ -0,0 +1,176 @@
+//! Threshold Schnorr (FROST-style) signing, offered as an alternative to the
+//! CGGMP21 ECDSA path in [`crate::signing`] for chains/ecosystems that expect
+//! a Schnorr signature over the same secret-shared key.
+//!
+//! This mirrors the two-round FROST structure: round 1 each signer samples a
+//! nonce pair and broadcasts commitments to it; round 2 every signer derives
+//! a per-signer binding factor over the full commitment list, forms the group
+//! nonce `R`, the challenge `c`, and its signature share `z_i`; a combiner
+//! sums the shares into the final `(R, z)` signature.
+//!
+//! Gated behind the `frost` feature so CGGMP21-only deployments don't pull in
+//! the extra machinery.
+
+use generic_ec::{Curve, NonZero, Point, Scalar, SecretScalar};
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// Commitments a signer publishes in round 1: `D_i = d_i * G`, `E_i = e_i * G`.
+#[derive(Debug, Clone, Copy)]
+pub struct NonceCommitment<E: Curve> {
+    pub party_index: u16,
+    pub d: Point<E>,
+    pub e: Point<E>,
+}
+
+/// The secret nonces backing a [`NonceCommitment`], kept by the signer
+/// between round 1 and round 2.
+pub struct SigningNonces<E: Curve> {
+    d: NonZero<SecretScalar<E>>,
+    e: NonZero<SecretScalar<E>>,
+}
+
+/// Round 1: sample a fresh nonce pair and the commitment to publish.
+pub fn commit<E: Curve>(
+    rng: &mut (impl RngCore + CryptoRng),
+    party_index: u16,
+) -> (SigningNonces<E>, NonceCommitment<E>) {
+    let d = NonZero::<SecretScalar<E>>::random(rng);
+    let e = NonZero::<SecretScalar<E>>::random(rng);
+
+    let commitment = NonceCommitment {
+        party_index,
+        d: Point::generator() * d.as_ref(),
+        e: Point::generator() * e.as_ref(),
+    };
+
+    (SigningNonces { d, e }, commitment)
+}
+
+/// Derives the per-signer binding factor `ρ_i = H(i, msg, B)` over the full
+/// list of round-1 commitments `B`.
+///
+/// `commitments` is part of the hash input in whatever order it's given, so
+/// every signer and the combiner must pass the exact same ordering (e.g.
+/// sorted by `party_index`, as the caller is responsible for doing before
+/// calling [`sign_share`]/[`combine`]) - two signers who disagree on the
+/// order will derive different `ρ_i`/`R` and silently produce an invalid
+/// signature instead of an error.
+fn binding_factor<E: Curve>(party_index: u16, msg: &[u8], commitments: &[NonceCommitment<E>]) -> Scalar<E> {
+    let mut hasher = Sha256::new();
+    hasher.update(party_index.to_be_bytes());
+    hasher.update(msg);
+    for c in commitments {
+        hasher.update(c.party_index.to_be_bytes());
+        hasher.update(c.d.to_bytes(true));
+        hasher.update(c.e.to_bytes(true));
+    }
+    Scalar::from_be_bytes_mod_order(hasher.finalize())
+}
+
+/// Forms the group commitment `R = Σ (D_i + ρ_i · E_i)` from every signer's
+/// round-1 commitment.
+fn group_commitment<E: Curve>(msg: &[u8], commitments: &[NonceCommitment<E>]) -> Point<E> {
+    commitments
+        .iter()
+        .map(|c| {
+            let rho = binding_factor(c.party_index, msg, commitments);
+            c.d + c.e * rho
+        })
+        .fold(Point::zero(), |acc, p| acc + p)
+}
+
+/// Derives the FROST challenge `c = H(R, Y, msg)` binding the group
+/// commitment, the group public key, and the message together.
+fn challenge<E: Curve>(r: Point<E>, group_public_key: Point<E>, msg: &[u8]) -> Scalar<E> {
+    let mut hasher = Sha256::new();
+    hasher.update(r.to_bytes(true));
+    hasher.update(group_public_key.to_bytes(true));
+    hasher.update(msg);
+    Scalar::from_be_bytes_mod_order(hasher.finalize())
+}
+
+/// Round 2: computes this signer's signature share
+/// `z_i = d_i + ρ_i·e_i + c·λ_i·x_i`, where `λ_i` is the same Lagrange
+/// coefficient the CGGMP21 ECDSA path already derives for this signer.
+///
+/// `commitments` must be the exact same slice (same contents, same order)
+/// every other signer and [`combine`] use for this signing session - see
+/// [`binding_factor`].
+pub fn sign_share<E: Curve>(
+    party_index: u16,
+    msg: &[u8],
+    commitments: &[NonceCommitment<E>],
+    nonces: &SigningNonces<E>,
+    group_public_key: Point<E>,
+    lambda_i: Scalar<E>,
+    secret_share: &SecretScalar<E>,
+) -> Scalar<E> {
+    let rho_i = binding_factor(party_index, msg, commitments);
+    let r = group_commitment(msg, commitments);
+    let c = challenge(r, group_public_key, msg);
+
+    nonces.d.as_ref() + rho_i * nonces.e.as_ref() + c * lambda_i * secret_share
+}
+
+/// Combines every signer's share into the final Schnorr signature `(R, z)`.
+///
+/// `commitments` must be the same slice (same contents, same order) every
+/// signer used in their own [`sign_share`] call - see [`binding_factor`].
+pub fn combine<E: Curve>(msg: &[u8], commitments: &[NonceCommitment<E>], shares: &[Scalar<E>]) -> (Point<E>, Scalar<E>) {
+    let r = group_commitment(msg, commitments);
+    let z = shares.iter().fold(Scalar::zero(), |acc, z_i| acc + z_i);
+    (r, z)
+}
+
+/// Verifies a combined Schnorr signature against the group public key:
+/// checks that `z·G == R + c·Y`.
+pub fn verify<E: Curve>(msg: &[u8], group_public_key: Point<E>, r: Point<E>, z: Scalar<E>) -> bool {
+    let c = challenge(r, group_public_key, msg);
+    Point::generator() * z == r + group_public_key * c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cggmp21::supported_curves::Secp256k1;
+
+    /// Full two-round FROST flow for 3 signers holding an additive
+    /// secret-sharing of a single key (each signer's share is an independent
+    /// random scalar and the group key is the sum of their public points, so
+    /// every `λ_i == 1`, keeping the test focused on
+    /// `commit`/`sign_share`/`combine`/`verify` rather than Lagrange
+    /// interpolation), proving the combined signature actually verifies -
+    /// the one thing nothing in this module exercised before.
+    #[test]
+    fn three_party_roundtrip_produces_a_verifiable_signature() {
+        let mut rng = rand::rngs::OsRng;
+        let msg = b"frost roundtrip test message";
+        let lambda_i = Scalar::<Secp256k1>::from_be_bytes_mod_order(&[1u8]);
+
+        let secret_shares: Vec<SecretScalar<Secp256k1>> =
+            (0..3).map(|_| SecretScalar::<Secp256k1>::random(&mut rng)).collect();
+        let group_public_key: Point<Secp256k1> = secret_shares
+            .iter()
+            .fold(Point::zero(), |acc, x_i| acc + Point::generator() * x_i);
+
+        let (nonces, commitments): (Vec<_>, Vec<_>) = (0..3u16).map(|i| commit::<Secp256k1>(&mut rng, i)).unzip();
+
+        let shares: Vec<Scalar<Secp256k1>> = (0..3usize)
+            .map(|i| {
+                sign_share(
+                    i as u16,
+                    msg,
+                    &commitments,
+                    &nonces[i],
+                    group_public_key,
+                    lambda_i,
+                    &secret_shares[i],
+                )
+            })
+            .collect();
+
+        let (r, z) = combine(msg, &commitments, &shares);
+        assert!(verify(msg, group_public_key, r, z), "combined FROST signature should verify");
+    }
+}
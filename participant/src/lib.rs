@@ -1,13 +1,24 @@
+mod authz;
 mod client;
 mod config;
+mod cosmos;
+mod dkg;
+mod external_verifier;
+#[cfg(feature = "frost")]
+mod frost;
+mod lock;
+mod presign;
+mod proxy;
 mod signing;
+mod tls;
 
 use std::error::Error;
 use std::collections::HashMap;
 use std::sync::Arc;
+use alloy::primitives::Address;
 use log::info;
 
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock};
 use cggmp21::KeyShare;
 use cggmp21::security_level::SecurityLevel128;
 use cggmp21::supported_curves::Secp256k1;
@@ -16,9 +27,19 @@ use proto::mpc::{Chain, SignMessage, SignatureMessage};
 use tonic::{Request, Response, Status, transport::Server};
 use reqwest::Url;
 
-pub use client::Client;
+pub use authz::RequesterPolicy;
+pub use client::{Client, ReconnectPolicy, TransportMode};
 pub use config::{AppConfig, ParticipantConfig, SSEConfig};
-pub use signing::Signing;
+pub use cosmos::{cosmos_address, cosmos_sighash, to_cosmos_signature};
+pub use dkg::{run_key_refresh, run_keygen};
+pub use external_verifier::{public_key_to_eth_address, Bitcoin as BitcoinVerifier, Ethereum as EthereumVerifier, ExternalVerifier};
+#[cfg(feature = "frost")]
+pub use frost::{NonceCommitment, SigningNonces};
+pub use lock::{InstanceLock, LockError};
+pub use presign::{AggregatedCommitments, PresignatureEntry, PresignaturePool, RefillThresholds};
+pub use proxy::ProxyConfig;
+pub use signing::{recover_address, to_bitcoin_der, to_compact_recoverable, x_only_public_key, EthereumSignature, Signing};
+pub use tls::TlsConfig;
 
 /// Main participant server structure that can be used as a library
 #[derive(Clone)]
@@ -26,39 +47,185 @@ pub struct ParticipantServer {
     server_address: String,
     handler: Arc<ParticipantHandler>,
     server_handle: Arc<Mutex<Option<tokio::sync::oneshot::Sender<()>>>>,
+    /// Fired from `start` once `serve_with_shutdown` has returned, i.e. a
+    /// clean exit with in-flight requests drained. `completion_receiver`
+    /// hands out the matching receiver so a caller like
+    /// `mpc_client::Signer::shutdown_local_participant` can tell a clean
+    /// exit apart from one it had to force via a timeout.
+    completion_tx: Arc<Mutex<Option<tokio::sync::oneshot::Sender<()>>>>,
+    /// TLS material for this server's own gRPC endpoint, if mutual TLS is
+    /// configured. The same `TlsConfig` that secures the outbound SSE/HTTP
+    /// transport (see [`Self::new_with_tls`]) also presents this service's
+    /// identity here, since every participant is both a client of the relay
+    /// and a server to the gateway's upstream connection.
+    tls: Option<TlsConfig>,
+    /// Single-instance guard for the account ids this server loaded key
+    /// shares for (see [`InstanceLock`]), held for the server's lifetime and
+    /// released on drop; shared across clones so it's only released once the
+    /// last one goes away.
+    _instance_lock: Arc<InstanceLock>,
 }
 
 /// Internal participant handler
 #[derive(Clone)]
 pub struct ParticipantHandler {
     client: Client,
-    key_shares: Arc<HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>>,  // account_id -> key_share映射
+    /// account_id -> key_share映射. Behind a lock rather than fixed at
+    /// construction so [`Self::add_key_share`]/[`Self::remove_key_share`]
+    /// can change it at runtime; a `sign_tx` call only holds the read lock
+    /// long enough to clone the one share it needs, so it never blocks, or
+    /// is blocked by, an unrelated account being added or removed.
+    key_shares: Arc<RwLock<HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>>>,
+    /// Per-account allowlist of requester addresses authorized to ask this
+    /// handler to sign. Empty means authorization is not configured (legacy/
+    /// demo mode), in which case every request is accepted but a warning is
+    /// logged.
+    policy: Arc<RequesterPolicy>,
+    /// Optional presignature pool backing this handler. When set, a signing
+    /// request can draw a pre-generated presignature instead of running the
+    /// full multi-round protocol online.
+    presign_pool: Option<Arc<PresignaturePool>>,
+    /// Total number of parties in this account's signing group, i.e. the
+    /// participant indices (`0..total_participants`) `sign_tx` runs the
+    /// protocol across. Defaults to 2 (see [`Self::with_total_participants`]),
+    /// matching every deployment this handler supported before that method
+    /// existed.
+    total_participants: u16,
+    /// Per-account set of party addresses allowed into that account's DKG/
+    /// refresh/signing rooms (see [`Self::set_room_members`]). An account
+    /// with no entry here keeps the legacy unrestricted-room behavior -
+    /// `register_room`/`RoomPolicy` are only exercised for accounts an
+    /// operator has explicitly opted in by calling `set_room_members`.
+    room_members: Arc<RwLock<HashMap<String, Vec<Address>>>>,
 }
 
 impl ParticipantHandler {
     /// Create a new participant handler with pre-loaded key shares
     pub fn new(client: Client, key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>) -> Result<Self, Box<dyn Error>> {
+        Self::new_with_policy(client, key_shares, RequesterPolicy::empty())
+    }
+
+    /// Create a new participant handler with pre-loaded key shares and a
+    /// requester authorization policy
+    pub fn new_with_policy(
+        client: Client,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+    ) -> Result<Self, Box<dyn Error>> {
         if key_shares.is_empty() {
             return Err("Key shares cannot be empty".into());
         }
-        
+
         info!("✓ Participant handler initialized successfully");
         info!("  - Loaded {} key shares", key_shares.len());
         info!("  - Available account_ids: {:?}", key_shares.keys().collect::<Vec<_>>());
-        
+
         Ok(Self {
             client,
-            key_shares: Arc::new(key_shares),
+            key_shares: Arc::new(RwLock::new(key_shares)),
+            policy: Arc::new(policy),
+            presign_pool: None,
+            total_participants: 2,
+            room_members: Arc::new(RwLock::new(HashMap::new())),
         })
     }
-    
-    /// Get key share and index for a specific account_id
-    fn get_key_share_by_account_id(&self, account_id: &str) -> Result<(&KeyShare<Secp256k1, SecurityLevel128>, u16), Box<dyn Error>> {
-        let key_share = self.key_shares.get(account_id)
+
+    /// Get an owned clone of the key share and index for a specific
+    /// account_id, so the caller never holds the `key_shares` lock across
+    /// the signing round that follows.
+    async fn get_key_share_by_account_id(&self, account_id: &str) -> Result<(KeyShare<Secp256k1, SecurityLevel128>, u16), Box<dyn Error>> {
+        let key_shares = self.key_shares.read().await;
+        let key_share = key_shares.get(account_id)
             .ok_or_else(|| format!("Key share not found for account_id: {}", account_id))?;
-        
+
         let index = key_share.core.i;
-        Ok((key_share, index))
+        Ok((key_share.clone(), index))
+    }
+
+    /// Adds (or replaces) `account_id`'s key share, making it available to
+    /// the next `sign_tx` call without restarting the participant. Lets a
+    /// new wallet account be provisioned at runtime.
+    pub async fn add_key_share(&self, account_id: String, key_share: KeyShare<Secp256k1, SecurityLevel128>) {
+        let replaced = self.key_shares.write().await.insert(account_id.clone(), key_share).is_some();
+        info!(
+            "{} key share for account_id {}",
+            if replaced { "Replaced" } else { "Added" },
+            account_id
+        );
+    }
+
+    /// Removes `account_id`'s key share, returning whether it was present.
+    /// A `sign_tx` call already in flight for this account is unaffected,
+    /// since it already holds its own clone of the share; only the next call
+    /// for it will see it gone.
+    pub async fn remove_key_share(&self, account_id: &str) -> bool {
+        let removed = self.key_shares.write().await.remove(account_id).is_some();
+        if removed {
+            info!("Removed key share for account_id {}", account_id);
+        }
+        removed
+    }
+
+    /// Lists the account ids this handler currently has key shares for.
+    pub async fn list_account_ids(&self) -> Vec<String> {
+        self.key_shares.read().await.keys().cloned().collect()
+    }
+
+    /// Locks `account_id`'s DKG/refresh/signing rooms to exactly `members`,
+    /// registering each one with the relay before the first join instead of
+    /// leaving it an unrestricted room anyone who learns its name can post
+    /// into. This client must have been built with [`Client::with_identity`]
+    /// (see [`crate::client::Room::register`]), since the registration
+    /// itself must be signed by one of `members`.
+    pub async fn set_room_members(&self, account_id: String, members: Vec<Address>) {
+        info!("Configured {} room member(s) for account_id {}", members.len(), account_id);
+        self.room_members.write().await.insert(account_id, members);
+    }
+
+    /// `account_id`'s configured room members, or an empty `Vec` if
+    /// [`Self::set_room_members`] was never called for it - the legacy,
+    /// unrestricted-room default.
+    async fn get_room_members(&self, account_id: &str) -> Vec<Address> {
+        self.room_members.read().await.get(account_id).cloned().unwrap_or_default()
+    }
+
+    /// Proactively re-randomizes `account_id`'s key share via
+    /// [`crate::dkg::run_key_refresh`] (see there for the invariants this
+    /// preserves), then swaps the in-memory share for the refreshed one. The
+    /// old share is only replaced after `run_key_refresh` has already
+    /// verified the public key is unchanged and persisted the new share to
+    /// `output_path`; a failed or aborted refresh leaves this handler (and
+    /// `output_path`) exactly as it was.
+    pub async fn refresh_key_share(
+        &self,
+        account_id: &str,
+        execution_id: &[u8],
+        output_path: impl AsRef<std::path::Path>,
+    ) -> Result<KeyShare<Secp256k1, SecurityLevel128>, Box<dyn Error>> {
+        let (key_share, _index) = self.get_key_share_by_account_id(account_id).await?;
+        let members = self.get_room_members(account_id).await;
+        let refreshed = crate::dkg::run_key_refresh(&self.client, account_id, execution_id, &key_share, output_path, &members)
+            .await
+            .map_err(|e| format!("key refresh failed for account_id {}: {:#}", account_id, e))?;
+        self.add_key_share(account_id.to_string(), refreshed.clone()).await;
+        Ok(refreshed)
+    }
+
+    /// Attaches a presignature pool to this handler, enabling the refill
+    /// background task and future online-round collapse once a request can
+    /// draw a pre-generated presignature instead of running all rounds live.
+    pub fn with_presignature_pool(mut self, pool: PresignaturePool) -> Self {
+        self.presign_pool = Some(Arc::new(pool));
+        self
+    }
+
+    /// Overrides the size of this account's signing group from the default
+    /// of 2, so `sign_tx` runs the protocol across participant indices
+    /// `0..total_participants` instead of always just `[0, 1]`. Must match
+    /// the party count the key shares were generated/refreshed with.
+    pub fn with_total_participants(mut self, total_participants: u16) -> Self {
+        self.total_participants = total_participants;
+        self
     }
 }
 
@@ -68,6 +235,17 @@ impl Participant for ParticipantHandler {
         &self,
         request: Request<SignMessage>,
     ) -> Result<Response<SignatureMessage>, Status> {
+        // Detached signature over (tx_id, chain, account_id, message_digest,
+        // execution_id, derivation_path) from the requester, carried as
+        // binary gRPC metadata so the generated SignMessage type doesn't
+        // need to change.
+        let requester_signature = request
+            .metadata()
+            .get_bin("x-requester-signature-bin")
+            .map(|value| value.to_bytes())
+            .transpose()
+            .map_err(|_| Status::invalid_argument("malformed x-requester-signature-bin metadata"))?;
+
         let req = request.into_inner();
 
         let tx_id = req.tx_id;
@@ -81,22 +259,54 @@ impl Participant for ParticipantHandler {
             return Err(Status::invalid_argument("account_id cannot be empty"));
         }
 
-        info!("Processing sign request - tx_id: {}, chain: {:?}, account_id: {}", 
+        info!("Processing sign request - tx_id: {}, chain: {:?}, account_id: {}",
               tx_id, chain, account_id);
 
+        if self.policy.is_empty() {
+            log::warn!("Requester authorization is not configured; accepting sign_tx without verifying the caller");
+        } else {
+            let signature_bytes = requester_signature.ok_or_else(|| {
+                log::warn!("Rejected sign request for account_id {}: missing requester signature", account_id);
+                Status::unauthenticated("missing x-requester-signature-bin metadata")
+            })?;
+
+            if signature_bytes.len() != 65 {
+                return Err(Status::invalid_argument("requester signature must be 65 bytes (r || s || v)"));
+            }
+            let mut r = [0u8; 32];
+            let mut s = [0u8; 32];
+            r.copy_from_slice(&signature_bytes[..32]);
+            s.copy_from_slice(&signature_bytes[32..64]);
+            let signature = EthereumSignature { r, s, v: signature_bytes[64] };
+
+            // No derivation_path is used for pre-derived, account-scoped key shares.
+            self.policy
+                .verify_request(&tx_id, req.chain, &account_id, &tx, &execution_id, &[], &signature)
+                .map_err(|e| {
+                    log::warn!("Rejected unauthorized sign request for account_id {}: {}", account_id, e);
+                    Status::permission_denied("requester is not authorized for this account")
+                })?;
+        }
+
         // 通过account_id获取对应的key_share和index
         let (key_share, signing_index) = self.get_key_share_by_account_id(&account_id)
+            .await
             .map_err(|e| {
                 log::error!("Failed to get key share for account_id {}: {}", account_id, e);
                 Status::not_found(format!("Key share not found for account_id: {}", account_id))
             })?;
 
         let signing = Signing::new(&self.client, tx_id);
+        let participants: Vec<u16> = (0..self.total_participants).collect();
+        let room_members = self.get_room_members(&account_id).await;
+        if room_members.is_empty() {
+            log::warn!("Room membership is not configured for account_id {}; signing room accepts any party that learns its name", account_id);
+        }
 
         // 使用account_id对应的key_share和index进行签名
         // 注意：现在不再需要derivation_path，因为每个account_id对应的key_share已经是派生后的
         let (r, s, v) = signing
-            .sign_tx(signing_index, &execution_id, &tx, key_share.clone(), chain, None)
+            .sign_tx(signing_index, &participants, &execution_id, &tx, key_share, chain, None, &room_members)
             .await
             .map_err(|e| {
                 log::error!("Transaction signing failed: {}", e);
@@ -113,21 +323,170 @@ impl Participant for ParticipantHandler {
 impl ParticipantServer {
     /// Create a new ParticipantServer with pre-loaded key shares
     pub fn new(sse_url: &str, participant_host: &str, participant_port: u16, key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>) -> Result<Self, Box<dyn Error>> {
-        info!("Initializing ParticipantServer");
+        Self::new_with_policy(sse_url, participant_host, participant_port, key_shares, RequesterPolicy::empty())
+    }
+
+    /// Create a new ParticipantServer with pre-loaded key shares and a
+    /// requester authorization policy. Use this when requester signatures
+    /// should be verified before a signing job ever enters the MPC protocol.
+    pub fn new_with_policy(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+    ) -> Result<Self, Box<dyn Error>> {
+        let client = Client::new(Url::parse(sse_url)?)?;
+        Self::from_client(client, participant_host, participant_port, key_shares, policy)
+    }
 
-        let server_url = Url::parse(sse_url)?;
-        let client = Client::new(server_url)?;
+    /// Same as [`Self::new_with_policy`], but secures the SSE/HTTP transport
+    /// with mutual TLS using `tls` instead of a plain connection, and serves
+    /// this server's own gRPC endpoint (see [`Self::start`]) over TLS with
+    /// the same certificate/CA material.
+    pub fn new_with_tls(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+        tls: &TlsConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::new_with_transport(sse_url, participant_host, participant_port, key_shares, policy, Some(tls), None, None, None)
+    }
+
+    /// Same as [`Self::new_with_policy`], but routes the SSE/HTTP transport
+    /// through a SOCKS5 proxy (e.g. Tor) instead of dialing `sse_url`
+    /// directly; see [`ProxyConfig`].
+    pub fn new_with_proxy(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+        proxy: &ProxyConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::new_with_transport(sse_url, participant_host, participant_port, key_shares, policy, None, None, None, Some(proxy))
+    }
+
+    /// Same as [`Self::new_with_policy`], but overrides the default SSE
+    /// reconnect policy (see [`ReconnectPolicy`]) instead of using the one
+    /// `Client::new` picks.
+    pub fn new_with_reconnect_policy(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+        reconnect: ReconnectPolicy,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::new_with_transport(sse_url, participant_host, participant_port, key_shares, policy, None, Some(reconnect), None, None)
+    }
+
+    /// Same as [`Self::new_with_policy`], but sends outgoing protocol
+    /// messages using `mode` (see [`TransportMode`]) instead of the default
+    /// one-POST-per-message behavior.
+    pub fn new_with_transport_mode(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+        mode: TransportMode,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::new_with_transport(sse_url, participant_host, participant_port, key_shares, policy, None, None, Some(mode), None)
+    }
+
+    /// General entry point behind [`Self::new_with_tls`],
+    /// [`Self::new_with_proxy`], [`Self::new_with_reconnect_policy`] and
+    /// [`Self::new_with_transport_mode`]: builds the SSE/HTTP `Client` with
+    /// the given TLS, proxy, reconnect and/or transport mode overrides
+    /// applied, any of which may be omitted to keep that setting at its
+    /// default.
+    pub fn new_with_transport(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+        tls: Option<&TlsConfig>,
+        reconnect: Option<ReconnectPolicy>,
+        transport_mode: Option<TransportMode>,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut client = Client::new_with_options(Url::parse(sse_url)?, tls, proxy)?;
+        if let Some(reconnect) = reconnect {
+            client = client.with_reconnect_policy(reconnect);
+        }
+        if let Some(transport_mode) = transport_mode {
+            client = client.with_transport_mode(transport_mode);
+        }
+
+        let mut server = Self::from_client(client, participant_host, participant_port, key_shares, policy)?;
+        server.tls = tls.cloned();
+        Ok(server)
+    }
+
+    fn from_client(
+        client: Client,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+    ) -> Result<Self, Box<dyn Error>> {
+        info!("Initializing ParticipantServer");
 
-        let handler = ParticipantHandler::new(client, key_shares)?;
         let server_address = format!("{}:{}", participant_host, participant_port);
 
+        // Refuse to start a second instance of this server for the same
+        // account ids; released when every clone of the returned
+        // `ParticipantServer` (and thus this `Arc`) is dropped.
+        let account_ids: Vec<String> = key_shares.keys().cloned().collect();
+        let instance_lock = InstanceLock::acquire(&account_ids, &server_address)
+            .map_err(|e| format!("Failed to acquire single-instance lock for this participant's key shares: {:#}", e))?;
+
+        let handler = ParticipantHandler::new_with_policy(client, key_shares, policy)?;
+
         Ok(Self {
             server_address,
             handler: Arc::new(handler),
             server_handle: Arc::new(Mutex::new(None)),
+            completion_tx: Arc::new(Mutex::new(None)),
+            tls: None,
+            _instance_lock: Arc::new(instance_lock),
         })
     }
 
+    /// Create a new ParticipantServer that also maintains a persistent
+    /// presignature pool, refilled by a background task once `start` runs.
+    pub fn new_with_presign_pool(
+        sse_url: &str,
+        participant_host: &str,
+        participant_port: u16,
+        key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>,
+        policy: RequesterPolicy,
+        pool: PresignaturePool,
+    ) -> Result<Self, Box<dyn Error>> {
+        let server = Self::new_with_policy(sse_url, participant_host, participant_port, key_shares, policy)?;
+        let handler = (*server.handler).clone().with_presignature_pool(pool);
+        Ok(Self {
+            handler: Arc::new(handler),
+            ..server
+        })
+    }
+
+    /// Overrides the size of this account's signing group from the default
+    /// of 2 (see [`ParticipantHandler::with_total_participants`]). Call
+    /// before [`Self::start`]; every other participant for these accounts
+    /// must agree on the same value.
+    pub fn with_total_participants(self, total_participants: u16) -> Self {
+        let handler = (*self.handler).clone().with_total_participants(total_participants);
+        Self {
+            handler: Arc::new(handler),
+            ..self
+        }
+    }
+
     /// Start the participant server
     pub async fn start(&self) -> Result<(), Box<dyn Error>> {
         let addr = self.server_address.parse()
@@ -145,7 +504,19 @@ impl ParticipantServer {
 
         let handler = self.handler.clone();
 
-        Server::builder()
+        if let Some(pool) = handler.presign_pool.clone() {
+            let account_ids = handler.list_account_ids().await;
+            tokio::spawn(presign_refill_loop(pool, account_ids));
+        }
+
+        let mut builder = Server::builder();
+        if let Some(tls) = &self.tls {
+            builder = builder
+                .tls_config(tls.to_tonic_server_config().map_err(|e| format!("Failed to configure gRPC server TLS: {}", e))?)
+                .map_err(|e| format!("Failed to apply gRPC server TLS config: {}", e))?;
+        }
+
+        builder
             .add_service(GrpcParticipantServer::new(handler.as_ref().clone()))
             .serve_with_shutdown(addr, async {
                 rx.await.ok();
@@ -156,6 +527,10 @@ impl ParticipantServer {
 
         info!("MPC participant service stopped");
 
+        if let Some(tx) = self.completion_tx.lock().await.take() {
+            let _ = tx.send(());
+        }
+
         Ok(())
     }
 
@@ -178,13 +553,89 @@ impl ParticipantServer {
         }
     }
 
+    /// Registers for a one-time signal that fires once `start` has cleanly
+    /// returned from `serve_with_shutdown` with in-flight requests drained.
+    /// Callers should take this receiver before calling `shutdown`, so
+    /// there's no race between the signal firing and the receiver being
+    /// registered for it.
+    pub async fn completion_receiver(&self) -> tokio::sync::oneshot::Receiver<()> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        *self.completion_tx.lock().await = Some(tx);
+        rx
+    }
+
     /// Get the server address
     pub fn address(&self) -> &str {
         &self.server_address
     }
 
     /// Get available account IDs
-    pub fn account_ids(&self) -> Vec<String> {
-        self.handler.key_shares.keys().cloned().collect()
+    pub async fn account_ids(&self) -> Vec<String> {
+        self.handler.list_account_ids().await
+    }
+
+    /// Adds (or replaces) an account's key share at runtime, making it
+    /// available to the next `sign_tx` call without restarting the server.
+    /// Lets a new wallet account be provisioned without a restart; mirrored
+    /// on `mpc_client::Signer`/`MpcSigner` so mobile hosts can do the same.
+    /// This method itself trusts its caller — whatever admin surface a
+    /// binary builds on top of it (gRPC, CLI, etc.) is responsible for
+    /// authenticating and authorizing whoever triggers it.
+    pub async fn add_key_share(&self, account_id: String, key_share: KeyShare<Secp256k1, SecurityLevel128>) {
+        self.handler.add_key_share(account_id, key_share).await
+    }
+
+    /// Removes an account's key share at runtime, returning whether it was
+    /// present. See [`Self::add_key_share`] for the authentication caveat.
+    pub async fn remove_key_share(&self, account_id: &str) -> bool {
+        self.handler.remove_key_share(account_id).await
+    }
+
+    /// Locks an account's DKG/refresh/signing rooms to exactly `members`
+    /// (see [`ParticipantHandler::set_room_members`]). See
+    /// [`Self::add_key_share`] for the authentication caveat, and
+    /// [`Client::with_identity`] - this server's client must have an
+    /// identity configured for room registration to succeed.
+    pub async fn set_room_members(&self, account_id: String, members: Vec<Address>) {
+        self.handler.set_room_members(account_id, members).await
+    }
+
+    /// Proactively re-randomizes `account_id`'s key share (see
+    /// [`ParticipantHandler::refresh_key_share`]), e.g. after a suspected
+    /// compromise of the on-disk share. Every other participant for this
+    /// account must call this with the same `execution_id` at the same
+    /// time, the same way [`run_keygen`]/[`run_key_refresh`] require.
+    pub async fn refresh_key_share(
+        &self,
+        account_id: &str,
+        execution_id: &[u8],
+        output_path: impl AsRef<std::path::Path>,
+    ) -> Result<KeyShare<Secp256k1, SecurityLevel128>, Box<dyn Error>> {
+        self.handler.refresh_key_share(account_id, execution_id, output_path).await
+    }
+}
+
+/// Periodically checks each account's presignature pool against its
+/// configured watermark and tops it up when low.
+///
+/// Actual presignature generation requires running the MPC presigning rounds
+/// with the other parties over the room transport; that protocol wiring is
+/// left as a follow-up, so for now this loop only reports the refill need.
+async fn presign_refill_loop(pool: Arc<PresignaturePool>, account_ids: Vec<String>) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+    loop {
+        interval.tick().await;
+        for account_id in &account_ids {
+            match pool.refill_need(account_id) {
+                Ok(Some(needed)) if needed > 0 => {
+                    info!(
+                        "Presignature pool for account_id {} is below watermark, needs {} more entries",
+                        account_id, needed
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => log::error!("Failed to check presignature pool for account_id {}: {}", account_id, e),
+            }
+        }
     }
 }
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+use std::{fs, process};
+
+use alloy::primitives::keccak256;
+use anyhow::{Context, Result};
+use log::{info, warn};
+use thiserror::Error;
+
+/// Advisory single-instance guard keyed on the exact set of account ids a
+/// [`crate::ParticipantServer`] loaded key shares for, so two processes can
+/// never drive independent MPC sessions off the same share. Held as a lock
+/// file under the system temp directory containing the holding process's PID
+/// and listen address; a lock whose PID is no longer alive is treated as
+/// stale and silently reclaimed.
+///
+/// This generalizes the key-share-file-path lock `sign-service` has used
+/// since its single-instance PID lock, to the account-id sets
+/// `ParticipantServer`/`Signer` actually hold in memory, so the guard also
+/// covers callers that load shares directly rather than from a file.
+pub struct InstanceLock {
+    path: PathBuf,
+}
+
+#[derive(Debug, Error)]
+pub enum LockError {
+    #[error(
+        "a participant is already running for account id(s) {account_ids:?} (pid {pid}, listening on {listen_addr}); lock file: {lock_path}"
+    )]
+    AlreadyRunning {
+        account_ids: Vec<String>,
+        pid: u32,
+        listen_addr: String,
+        lock_path: String,
+    },
+}
+
+impl InstanceLock {
+    /// Acquires the lock for `account_ids`, order-independent (the set, not
+    /// the order, determines the lock file). Fails with
+    /// [`LockError::AlreadyRunning`] if a live process already holds it;
+    /// reclaims the lock if the recorded PID is dead.
+    pub fn acquire(account_ids: &[String], listen_addr: &str) -> Result<Self> {
+        let path = lock_path(account_ids)?;
+
+        if let Some(held) = read_lock(&path)? {
+            if is_process_alive(held.pid) {
+                return Err(LockError::AlreadyRunning {
+                    account_ids: account_ids.to_vec(),
+                    pid: held.pid,
+                    listen_addr: held.listen_addr,
+                    lock_path: path.display().to_string(),
+                }
+                .into());
+            }
+            warn!(
+                "Reclaiming stale participant lock {} held by dead pid {}",
+                path.display(),
+                held.pid
+            );
+        }
+
+        fs::write(&path, format!("{}\n{}\n", process::id(), listen_addr))
+            .with_context(|| format!("Failed to write lock file {}", path.display()))?;
+
+        info!("Acquired single-instance lock at {}", path.display());
+        Ok(Self { path })
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        match fs::remove_file(&self.path) {
+            Ok(()) => info!("Released single-instance lock at {}", self.path.display()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Failed to remove lock file {}: {}", self.path.display(), e),
+        }
+    }
+}
+
+struct HeldLock {
+    pid: u32,
+    listen_addr: String,
+}
+
+/// Lock file path for a given account id set: the system temp directory
+/// (no `data_dir` concept at this layer, unlike `sign-service`'s file-keyed
+/// lock) with a file name derived from a digest of the sorted account ids,
+/// so the same set always maps to the same file regardless of `HashMap`
+/// iteration order.
+fn lock_path(account_ids: &[String]) -> Result<PathBuf> {
+    let mut sorted = account_ids.to_vec();
+    sorted.sort();
+
+    let mut buf = Vec::new();
+    for id in &sorted {
+        buf.extend_from_slice(id.as_bytes());
+        buf.push(0);
+    }
+    let digest = keccak256(&buf);
+
+    let dir = std::env::temp_dir().join("wallet-mpc-locks");
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create lock dir {}", dir.display()))?;
+    Ok(dir.join(format!("{:x}.lock", digest)))
+}
+
+fn read_lock(path: &Path) -> Result<Option<HeldLock>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            let mut lines = contents.lines();
+            let pid = lines.next().and_then(|line| line.trim().parse::<u32>().ok());
+            let listen_addr = lines.next().unwrap_or_default().trim().to_string();
+            Ok(pid.map(|pid| HeldLock { pid, listen_addr }))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("Failed to read lock file {}", path.display())),
+    }
+}
+
+/// Checks whether `pid` still belongs to a live process. Only implemented
+/// precisely on Linux (via `/proc`, so no extra dependency is needed);
+/// elsewhere we conservatively assume it's alive rather than risk reclaiming
+/// a lock out from under a running process.
+#[cfg(target_os = "linux")]
+fn is_process_alive(pid: u32) -> bool {
+    Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_process_alive(_pid: u32) -> bool {
+    true
+}
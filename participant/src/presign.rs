@@ -0,0 +1,275 @@
+//! Persistent pool of pre-generated presignatures, drawn one-per-request so
+//! that an online signing round collapses to the final combine step.
+//!
+//! Following the sequencer/validator pattern: every party that contributes to
+//! a pool entry publishes a `commitment` over its local presignature share,
+//! and an [`AggregationCoordinator`] checks that all parties agree on the
+//! same commitment for a given pool index before any partial signature is
+//! issued against it.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+/// A single pre-generated presignature entry, not yet consumed by a signing
+/// request.
+///
+/// `material` is the serialized per-party presignature share produced by the
+/// MPC presigning rounds (opaque to the pool itself); `commitment` is the
+/// value every party must agree on before the entry is usable, matching what
+/// `PartialSignature::combine` callers assert is identical across parties.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresignatureEntry {
+    pub index: u64,
+    pub commitment: Vec<u8>,
+    pub material: Vec<u8>,
+}
+
+/// On-disk representation of a single account's presignature pool.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PoolFile {
+    next_index: u64,
+    consumed: HashSet<u64>,
+    entries: VecDeque<PresignatureEntry>,
+}
+
+/// Configuration for how aggressively the pool keeps itself topped up.
+#[derive(Debug, Clone, Copy)]
+pub struct RefillThresholds {
+    /// Trigger a refill once the available (unconsumed) entry count drops to
+    /// or below this watermark.
+    pub low_watermark: usize,
+    /// Target pool size a refill should top the pool back up to.
+    pub target_size: usize,
+}
+
+impl Default for RefillThresholds {
+    fn default() -> Self {
+        Self {
+            low_watermark: 5,
+            target_size: 20,
+        }
+    }
+}
+
+/// Durable, per-account pool of presignatures for a `ParticipantServer`.
+///
+/// Each account gets its own backing file under `data_dir`, so a consumed
+/// index is never reused even across restarts.
+pub struct PresignaturePool {
+    data_dir: PathBuf,
+    thresholds: RefillThresholds,
+    /// Serializes every load-modify-save sequence across all accounts, so
+    /// two concurrent `draw`/`replenish` calls can't both read the same
+    /// on-disk state before either writes back - which, for `draw`, would
+    /// otherwise hand out the same presignature (and therefore the same
+    /// ECDSA nonce) to two callers at once.
+    lock: Mutex<()>,
+}
+
+impl PresignaturePool {
+    pub fn new<P: Into<PathBuf>>(data_dir: P, thresholds: RefillThresholds) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            thresholds,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn pool_path(&self, account_id: &str) -> PathBuf {
+        self.data_dir.join(format!("{account_id}.presigs.json"))
+    }
+
+    fn load(&self, account_id: &str) -> Result<PoolFile> {
+        let path = self.pool_path(account_id);
+        if !path.exists() {
+            return Ok(PoolFile::default());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read presignature pool file {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse presignature pool file {}", path.display()))
+    }
+
+    fn save(&self, account_id: &str, pool: &PoolFile) -> Result<()> {
+        fs::create_dir_all(&self.data_dir)
+            .with_context(|| format!("failed to create presignature pool directory {}", self.data_dir.display()))?;
+        let path = self.pool_path(account_id);
+        let tmp_path = path.with_extension("json.tmp");
+        let json = serde_json::to_string_pretty(pool).context("failed to serialize presignature pool")?;
+        fs::write(&tmp_path, json)
+            .with_context(|| format!("failed to write presignature pool file {}", tmp_path.display()))?;
+        fs::rename(&tmp_path, &path)
+            .with_context(|| format!("failed to persist presignature pool file {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Number of unconsumed presignatures currently available for an account.
+    pub fn available(&self, account_id: &str) -> Result<usize> {
+        Ok(self.load(account_id)?.entries.len())
+    }
+
+    /// Appends freshly generated presignatures (already validated by the
+    /// [`AggregationCoordinator`]) to an account's pool.
+    pub fn replenish(&self, account_id: &str, fresh: Vec<(Vec<u8>, Vec<u8>)>) -> Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut pool = self.load(account_id)?;
+        for (commitment, material) in fresh {
+            let index = pool.next_index;
+            pool.next_index += 1;
+            pool.entries.push_back(PresignatureEntry { index, commitment, material });
+        }
+        info!(
+            "Replenished presignature pool for account_id {}: {} entries available",
+            account_id,
+            pool.entries.len()
+        );
+        self.save(account_id, &pool)
+    }
+
+    /// Returns whether the pool should be refilled per the configured
+    /// watermark, and if so how many entries are needed to reach the target.
+    pub fn refill_need(&self, account_id: &str) -> Result<Option<usize>> {
+        let available = self.available(account_id)?;
+        if available <= self.thresholds.low_watermark {
+            Ok(Some(self.thresholds.target_size.saturating_sub(available)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Draws the next unconsumed presignature for an account, marking its
+    /// index as durably consumed so it can never be drawn again even across
+    /// restarts (e.g. after a crash between draw and use).
+    pub fn draw(&self, account_id: &str) -> Result<PresignatureEntry> {
+        let _guard = self.lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut pool = self.load(account_id)?;
+        let entry = pool
+            .entries
+            .pop_front()
+            .ok_or_else(|| anyhow::anyhow!("presignature pool for account_id {account_id} is empty"))?;
+
+        if !pool.consumed.insert(entry.index) {
+            anyhow::bail!("presignature index {} for account_id {} was already consumed", entry.index, account_id);
+        }
+
+        self.save(account_id, &pool)?;
+        Ok(entry)
+    }
+}
+
+/// Aggregates the presignature commitments published by every party for a
+/// given pool index and confirms they all agree before the entry is accepted
+/// into the pool.
+#[derive(Debug, Default)]
+pub struct AggregatedCommitments {
+    by_party: HashMap<u16, Vec<u8>>,
+}
+
+impl AggregatedCommitments {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the commitment published by `party_index`.
+    pub fn insert(&mut self, party_index: u16, commitment: Vec<u8>) {
+        self.by_party.insert(party_index, commitment);
+    }
+
+    /// Validates that every recorded party published the same commitment,
+    /// returning it if so.
+    pub fn validate(&self, expected_parties: &[u16]) -> Result<Vec<u8>> {
+        let mut commitments = expected_parties.iter().map(|party| {
+            self.by_party
+                .get(party)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("party {party} has not published a presignature commitment yet"))
+        });
+
+        let first = commitments.next().ok_or_else(|| anyhow::anyhow!("no expected parties supplied"))??;
+        for other in commitments {
+            let other = other?;
+            if other != first {
+                warn!("Presignature commitment mismatch between parties; refusing to aggregate");
+                anyhow::bail!("parties disagree on presignature commitment");
+            }
+        }
+        Ok(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Unique-per-test-process scratch directory, same convention
+    /// `InstanceLock`'s lock files use, so parallel test runs never collide.
+    fn scratch_dir(label: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("wallet-mpc-presign-test-{label}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn draw_never_hands_out_the_same_entry_twice() {
+        let dir = scratch_dir("draw-no-reuse");
+        let pool = PresignaturePool::new(&dir, RefillThresholds::default());
+        pool.replenish("acct", vec![(b"c0".to_vec(), b"m0".to_vec()), (b"c1".to_vec(), b"m1".to_vec())])
+            .unwrap();
+
+        let first = pool.draw("acct").unwrap();
+        let second = pool.draw("acct").unwrap();
+        assert_ne!(first.index, second.index);
+        assert!(pool.draw("acct").is_err(), "pool should be empty after two draws of two entries");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn draw_rejects_an_index_replayed_into_the_consumed_set() {
+        let dir = scratch_dir("draw-rejects-replay");
+        let pool = PresignaturePool::new(&dir, RefillThresholds::default());
+        pool.replenish("acct", vec![(b"c0".to_vec(), b"m0".to_vec())]).unwrap();
+
+        let entry = pool.draw("acct").unwrap();
+        // Simulate a crash-recovered pool file that still lists the
+        // already-consumed entry as available (e.g. a concurrent writer
+        // clobbered `consumed` without re-checking it first).
+        let mut pool_file = pool.load("acct").unwrap();
+        pool_file.entries.push_back(entry.clone());
+        pool.save("acct", &pool_file).unwrap();
+
+        assert!(pool.draw("acct").is_err(), "an index already in `consumed` must never be handed out again");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn aggregated_commitments_agree() {
+        let mut commitments = AggregatedCommitments::new();
+        commitments.insert(0, vec![1, 2, 3]);
+        commitments.insert(1, vec![1, 2, 3]);
+
+        assert_eq!(commitments.validate(&[0, 1]).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn aggregated_commitments_reject_disagreement() {
+        let mut commitments = AggregatedCommitments::new();
+        commitments.insert(0, vec![1, 2, 3]);
+        commitments.insert(1, vec![4, 5, 6]);
+
+        assert!(commitments.validate(&[0, 1]).is_err());
+    }
+
+    #[test]
+    fn aggregated_commitments_require_every_expected_party() {
+        let mut commitments = AggregatedCommitments::new();
+        commitments.insert(0, vec![1, 2, 3]);
+
+        assert!(commitments.validate(&[0, 1]).is_err());
+    }
+}
@@ -27,6 +27,204 @@ impl CurveParams for cggmp21::supported_curves::Secp256k1 {
     // type ExVerifier = external_verifier::Bitcoin;
 }
 
+/// Ethereum `ecrecover`-compatible ECDSA signature: `r || s || v`.
+///
+/// `v` is the raw recovery id (0 or 1), derived from the parity of the nonce
+/// point `R.y` and whether `r` was reduced mod the curve order when taking
+/// its affine x-coordinate.
+#[derive(Debug, Clone)]
+pub struct EthereumSignature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub v: u8,
+}
+
+impl EthereumSignature {
+    /// The raw recovery id (0 or 1) needed by `ecrecover`.
+    pub fn recovery_id(&self) -> u8 {
+        self.v
+    }
+
+    /// Serializes the signature into the 65-byte `r || s || v` form accepted
+    /// by Ethereum's `ecrecover` precompile.
+    pub fn to_eth_bytes(&self) -> [u8; 65] {
+        let mut out = [0u8; 65];
+        out[..32].copy_from_slice(&self.r);
+        out[32..64].copy_from_slice(&self.s);
+        out[64] = self.v;
+        out
+    }
+}
+
+/// Reconstructs the signer's public key from a message hash and signature, so
+/// callers can assert it matches the expected account before broadcasting.
+pub fn recover_public_key(
+    message_hash: &[u8],
+    signature: &EthereumSignature,
+) -> Result<VerifyingKey> {
+    let sig = Signature::from_slice(&[&signature.r[..], &signature.s[..]].concat())
+        .map_err(|err| anyhow::anyhow!("malformed signature: {err}"))?;
+    let recovery_id = RecoveryId::try_from(signature.v)
+        .map_err(|err| anyhow::anyhow!("invalid recovery id {}: {err}", signature.v))?;
+    VerifyingKey::recover_from_prehash(message_hash, &sig, recovery_id)
+        .map_err(|err| anyhow::anyhow!("public key recovery failed: {err}"))
+}
+
+/// Reconstructs the signer's public key from `(message_hash, r, s, v)` and
+/// derives the Ethereum checksum address it recovers to. `v` is accepted in
+/// whatever form a caller happens to have on hand: the raw recovery parity
+/// (0/1) `Signing::sign_tx` returns, the pre-EIP-155 `27`/`28`, or an
+/// EIP-155-encoded `chain_id*2 + 35/36` (see [`normalize_recovery_id`]).
+/// Lets a caller independently verify an MPC signature corresponds to the
+/// expected account address before broadcasting, instead of re-deriving it
+/// by hand the way the logging above does.
+pub fn recover_address(chain: Chain, message_hash: &[u8], r: &[u8], s: &[u8], v: u32) -> Result<Address> {
+    let recovery_id = match chain {
+        Chain::Ethereum => normalize_recovery_id(v)?,
+        Chain::Bitcoin => {
+            return Err(anyhow::anyhow!("address recovery is not implemented for Bitcoin yet"));
+        }
+    };
+
+    let sig = Signature::from_slice(&[r, s].concat())
+        .map_err(|err| anyhow::anyhow!("malformed signature: {err}"))?;
+    let recovered = VerifyingKey::recover_from_prehash(message_hash, &sig, recovery_id)
+        .map_err(|err| anyhow::anyhow!("public key recovery failed: {err}"))?;
+
+    let uncompressed = recovered.to_encoded_point(false);
+    let hash = keccak256(&uncompressed.as_bytes()[1..]);
+    Ok(Address::from_slice(&hash[12..]))
+}
+
+/// Converts a `v`/recovery-id value in any form Ethereum tooling uses back
+/// to the raw 0/1 recovery parity `RecoveryId` needs: unchanged if it's
+/// already 0/1, `v - 27` for pre-EIP-155 legacy (`27`/`28`), or `(v - 35) %
+/// 2` for EIP-155-encoded legacy (`chain_id*2 + 35/36`).
+fn normalize_recovery_id(v: u32) -> Result<RecoveryId> {
+    let parity = match v {
+        0 | 1 => v as u8,
+        27 | 28 => (v - 27) as u8,
+        v if v >= 35 => ((v - 35) % 2) as u8,
+        other => return Err(anyhow::anyhow!("unrecognized recovery id/v value: {other}")),
+    };
+    RecoveryId::try_from(parity).map_err(|err| anyhow::anyhow!("invalid recovery id {parity}: {err}"))
+}
+
+/// Serializes `(r, s)` as a strict, minimal-length DER
+/// `SEQUENCE { INTEGER r, INTEGER s }` with `sighash_type` appended, the
+/// form Bitcoin scripts/mempool policy expect (e.g. `0x01` for
+/// `SIGHASH_ALL`). Assumes `s` is already low-S normalized, which
+/// `Signing::sign_tx` now guarantees for every chain (see its BIP-62 note)
+/// and which Bitcoin standardness rules require anyway.
+pub fn to_bitcoin_der(r: &[u8], s: &[u8], sighash_type: u8) -> Vec<u8> {
+    fn encode_integer(bytes: &[u8], out: &mut Vec<u8>) {
+        let mut bytes = bytes;
+        while bytes.len() > 1 && bytes[0] == 0 {
+            bytes = &bytes[1..];
+        }
+        out.push(0x02); // INTEGER tag
+        if bytes[0] & 0x80 != 0 {
+            // High bit set: would read back as negative, so pad with a
+            // leading zero byte.
+            out.push((bytes.len() + 1) as u8);
+            out.push(0x00);
+        } else {
+            out.push(bytes.len() as u8);
+        }
+        out.extend_from_slice(bytes);
+    }
+
+    let mut body = Vec::new();
+    encode_integer(r, &mut body);
+    encode_integer(s, &mut body);
+
+    let mut out = Vec::with_capacity(body.len() + 3);
+    out.push(0x30); // SEQUENCE tag
+    out.push(body.len() as u8);
+    out.extend_from_slice(&body);
+    out.push(sighash_type);
+    out
+}
+
+/// Serializes `(r, s, recovery_id)` as the 65-byte compact recoverable form
+/// used across the k256/secp256k1 ecosystem: `header || r || s`, where
+/// `header = 27 + recovery_id + (4 if compressed)`.
+pub fn to_compact_recoverable(r: &[u8; 32], s: &[u8; 32], recovery_id: u8, compressed: bool) -> [u8; 65] {
+    let mut out = [0u8; 65];
+    out[0] = 27 + recovery_id + if compressed { 4 } else { 0 };
+    out[1..33].copy_from_slice(r);
+    out[33..65].copy_from_slice(s);
+    out
+}
+
+/// The x-only (BIP-340) coordinate of a pre-derived key share's shared
+/// public key, as needed for Taproot key-path spends.
+///
+/// This is as far as Taproot support goes without a protocol change: a
+/// genuine BIP-340 Schnorr signature needs its own nonce/challenge scalar
+/// arithmetic (`s = k + e*x`) run cooperatively across the threshold, which
+/// this function's ECDSA `(r, s)` output can't be converted into after the
+/// fact. Producing one means running the two-round Schnorr protocol already
+/// implemented in `crate::frost` (gated behind the `frost` feature) against
+/// this key share instead of `Signing::sign_tx`'s single-round CGGMP21 path.
+pub fn x_only_public_key<T>(key_share: &KeyShare<T, SecurityLevel128>) -> Result<[u8; 32]>
+where
+    T: Curve,
+    Point<T>: HasAffineX<T>,
+{
+    let x = key_share
+        .shared_public_key
+        .x()
+        .ok_or_else(|| anyhow::anyhow!("shared public key has no affine x-coordinate (point at infinity)"))?;
+    let bytes = x.to_be_bytes();
+    let mut out = [0u8; 32];
+    out.copy_from_slice(bytes.as_bytes());
+    Ok(out)
+}
+
+/// Recovers the 0/1 ECDSA recovery parity for `(r, s)` over `message_scalar`
+/// against `public_key_uncompressed`, trying `RecoveryId::trial_recovery_from_msg`
+/// first and falling back to brute-forcing both candidate parities through
+/// [`recover_public_key`] if that fails (can happen with low-S-normalized
+/// signatures on some inputs). Shared by every chain in
+/// [`Signing::sign_tx`] - the recovery math itself doesn't depend on chain,
+/// only on the curve (secp256k1 here).
+fn compute_recovery_id(
+    public_key_uncompressed: &[u8],
+    message_scalar: &[u8; 32],
+    r_bytes: &[u8],
+    s_bytes: &[u8],
+) -> Result<u8> {
+    let v_key = VerifyingKey::from_sec1_bytes(public_key_uncompressed)
+        .map_err(|err| anyhow::anyhow!("verifying key failed: {err}"))?;
+    let sig = Signature::from_slice(&[r_bytes, s_bytes].concat())
+        .map_err(|err| anyhow::anyhow!("signature failed: {err}"))?;
+
+    if let Ok(id) = RecoveryId::trial_recovery_from_msg(&v_key, message_scalar, &sig) {
+        log::info!("Recovery ID computed: {}", id.to_byte());
+        return Ok(id.to_byte());
+    }
+
+    log::warn!("⚠️ Primary recovery ID calculation failed, trying manual recovery");
+    let mut r_arr = [0u8; 32];
+    let mut s_arr = [0u8; 32];
+    r_arr.copy_from_slice(r_bytes);
+    s_arr.copy_from_slice(s_bytes);
+
+    for test_id in [0u8, 1u8] {
+        let candidate = EthereumSignature { r: r_arr, s: s_arr, v: test_id };
+        if let Ok(recovered_key) = recover_public_key(message_scalar, &candidate) {
+            if recovered_key.to_encoded_point(false).as_bytes() == public_key_uncompressed {
+                log::info!("✅ Correct recovery ID found through manual testing: {}", test_id);
+                return Ok(test_id);
+            }
+        }
+    }
+
+    log::error!("❌ Failed to determine correct recovery ID");
+    Err(anyhow::anyhow!("Cannot determine recovery ID"))
+}
+
 pub struct Signing {
     room: Room,
 }
@@ -41,11 +239,13 @@ impl Signing {
     pub async fn sign_tx<T>(
         self,
         index: u16,
+        participants: &[u16],
         execution_id: &[u8],
         tx: &[u8],
         key_share: KeyShare<T, SecurityLevel128>,
         chain: Chain,
         _derivation_path: Option<Vec<u32>>, // Reserved for compatibility, not used because key_share is pre-derived
+        members: &[Address],
     ) -> Result<(Vec<u8>, Vec<u8>, u32)>
     where
         T: Curve + CurveParams + cggmp21::hd_wallet::slip10::SupportedCurve,
@@ -53,6 +253,16 @@ impl Signing {
     {
         let eid = ExecutionId::new(execution_id);
 
+        // Locks this signing room to `members` before anyone joins it; a
+        // no-op when `members` is empty, the legacy unrestricted-room
+        // behavior for callers that haven't configured a member set (see
+        // `Client::with_identity`).
+        if !members.is_empty() {
+            self.room
+                .register_or_join(members.to_vec(), participants.len())
+                .await?;
+        }
+
         let (_, incoming, outgoing) = self.room.join_room::<Msg<T, Sha256>>(index).await?;
 
         let party = MpcParty::connected((incoming, outgoing));
@@ -61,9 +271,11 @@ impl Signing {
         // So we create DataToSign from the hash directly, not digest it again
         let data = DataToSign::from_scalar(generic_ec::Scalar::from_be_bytes_mod_order(tx));
 
-        // TODO: Harcoded parties_indexes_at_keygen. Participants has a harcoded index.
-        // Indexes must be issued on room creation and stored in DB.
-        let signing = cggmp21::signing(eid, index, &[0, 1], &key_share);
+        // `participants` is the full signing group for this account (see
+        // `ParticipantHandler::with_total_participants`), not a hardcoded
+        // pair, so this scales past 2 parties as long as every other
+        // participant's handler was configured with the same value.
+        let signing = cggmp21::signing(eid, index, participants, &key_share);
         
         // No need for HD wallet derivation anymore because key_share is pre-derived
         log::info!("Using pre-derived key share (account-specific)");
@@ -82,10 +294,33 @@ impl Signing {
         let r = signature.r.into_inner().to_be_bytes();
         let r_bytes = r.as_bytes();
         let s = signature.s.into_inner().to_be_bytes();
-        let s_bytes = s.as_bytes();
 
-        // Compute recovery ID (0 or 1) for signature verification
-        // Upper layers can convert this to chain-specific format (e.g., EIP-155 for Ethereum)
+        // EIP-2 (and BIP-62 for Bitcoin) reject/discourage a signature whose
+        // `s` lies in the upper half of the curve order, so canonicalize it
+        // to the low-S form here for every chain rather than gating it to
+        // Ethereum alone. `s` and `n - s` both verify against the same
+        // public key but under opposite recovery parity, so once this
+        // flips `s` the recovery-id search below (which recomputes against
+        // whatever `s` ends up being) naturally lands on the matching
+        // parity - no separate bit-flip needed.
+        let mut s_arr = [0u8; 32];
+        s_arr.copy_from_slice(s.as_bytes());
+        if let Ok(sig) = Signature::from_slice(&[r_bytes, &s_arr].concat()) {
+            if let Some(normalized) = sig.normalize_s() {
+                s_arr.copy_from_slice(normalized.s().to_bytes().as_slice());
+                log::info!("Normalized signature to low-S form (EIP-2/BIP-62)");
+            }
+        }
+        let s_bytes: &[u8] = &s_arr;
+
+        // Compute the raw recovery id (0 or 1), not a chain-specific `v`:
+        // `chain_signer(chain).verify` (mpc_client::chain) and
+        // `recover_public_key` above both need the bare parity to call
+        // `RecoveryId::try_from`/`recover_from_prehash`, so encoding it into
+        // Ethereum's `v` here would break them. Callers that need a
+        // broadcast-ready `v` do that conversion themselves afterwards, e.g.
+        // `mpc_client::ethereum::EthTransactionRequest::into_signed_bytes`
+        // (EIP-155 for legacy transactions, raw y_parity for EIP-2718 ones).
         let recovery_id = match chain {
             Chain::Ethereum => {
                 // Directly use the shared_public_key in the pre-derived key_share
@@ -116,68 +351,19 @@ impl Signing {
                     log::warn!("Invalid public key format for Ethereum address calculation");
                 }
 
-                // Compute recovery ID using k256 library
-                let pub_key = public_key.to_bytes(false);
-                let v_key = VerifyingKey::from_sec1_bytes(&pub_key).map_err(|err| {
-                    log::error!("Verifying key failed: {err}");
-                    if let Some(source) = err.source() {
-                        log::error!("Caused by: {}", source);
-                    }
-                    err
-                })?;
-                let sig = Signature::from_slice(&[r_bytes, s_bytes].concat()).map_err(|err| {
-                    log::error!("Signature failed: {err}");
-                    if let Some(source) = err.source() {
-                        log::error!("Caused by: {}", source);
-                    }
-                    err
-                })?;
-
-                // Try to recover the recovery ID from the signature
-                let recovery_id = RecoveryId::trial_recovery_from_msg(
-                    &v_key,
-                    &data.to_scalar().to_be_bytes(),
-                    &sig,
-                );
-
-                match recovery_id {
-                    Ok(id) => {
-                        log::info!("Recovery ID computed: {}", id.to_byte());
-                        id.to_byte() as u32
-                    }
-                    Err(_) => {
-                        log::warn!("⚠️ Primary recovery ID calculation failed, trying manual recovery");
-
-                        // Manually attempt recovery ID 0 and 1
-                        for test_id in [0u8, 1u8] {
-                            if let Ok(recovery_id) = RecoveryId::try_from(test_id) {
-                                if let Ok(recovered_key) = VerifyingKey::recover_from_prehash(
-                                    &data.to_scalar().to_be_bytes(),
-                                    &sig,
-                                    recovery_id,
-                                ) {
-                                    // 检查恢复的公钥是否匹配
-                                    let recovered_bytes = recovered_key.to_encoded_point(false);
-                                    let expected_bytes = public_key.to_bytes(false);
-
-                                    // Correctly compare two byte arrays
-                                    if recovered_bytes.as_bytes() == expected_bytes.as_ref() {
-                                        log::info!("✅ Correct recovery ID found through manual testing: {}", test_id);
-                                        return Ok((r_bytes.to_vec(), s_bytes.to_vec(), test_id as u32));
-                                    }
-                                }
-                            }
-                        }
-
-                        log::error!("❌ Failed to determine correct recovery ID");
-                        return Err(anyhow::anyhow!("Cannot determine recovery ID"));
-                    }
-                }
+                compute_recovery_id(&public_key.to_bytes(false), &data.to_scalar().to_be_bytes(), r_bytes, s_bytes)? as u32
             }
             Chain::Bitcoin => {
-                // Bitcoin doesn't use recovery ID in the same way
-                // Return 0 as placeholder
-                0
+                // ECDSA recovery works identically on secp256k1 regardless
+                // of chain; Bitcoin just doesn't standardize a wire format
+                // for the recovery id the way Ethereum's `v` does, so it's
+                // only useful here for `to_compact_recoverable`'s `header`.
+                compute_recovery_id(
+                    &key_share.shared_public_key.to_bytes(false),
+                    &data.to_scalar().to_be_bytes(),
+                    r_bytes,
+                    s_bytes,
+                )? as u32
             }
         };
 
@@ -0,0 +1,163 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Mutual-TLS material for a service's transports: a CA bundle used to
+/// verify the peer, and this service's own certificate/key, presented as a
+/// client certificate to peers that require one and as the server identity
+/// when this service itself accepts connections.
+///
+/// Because MPC participants are a fixed, known set, every one of them can be
+/// minted a certificate signed by the deployment's own CA ahead of time, so
+/// unknown peers can be rejected outright rather than merely encrypted past.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TlsConfig {
+    pub ca_cert_path: String,
+    #[serde(default)]
+    pub client_cert_path: Option<String>,
+    #[serde(default)]
+    pub client_key_path: Option<String>,
+    /// Whether a server using this config should require and verify a
+    /// client certificate from every peer, rejecting unauthenticated
+    /// connections instead of merely encrypting them.
+    #[serde(default)]
+    pub require_client_auth: bool,
+}
+
+impl TlsConfig {
+    /// Builds a rustls `ClientConfig` that trusts only `ca_cert_path` and, if
+    /// a client certificate/key is configured, presents it to the peer. Used
+    /// to secure the reqwest-based SSE/HTTP transport via
+    /// `reqwest::ClientBuilder::use_preconfigured_tls`.
+    pub fn to_rustls_client_config(&self) -> Result<rustls::ClientConfig> {
+        let mut roots = rustls::RootCertStore::empty();
+        for cert in load_certs(&self.ca_cert_path)? {
+            roots
+                .add(cert)
+                .context("failed to add CA certificate to root store")?;
+        }
+
+        let builder = rustls::ClientConfig::builder().with_root_certificates(roots);
+
+        let config = match (&self.client_cert_path, &self.client_key_path) {
+            (Some(cert_path), Some(key_path)) => {
+                let certs = load_certs(cert_path)?;
+                let key = load_private_key(key_path)?;
+                builder
+                    .with_client_auth_cert(certs, key)
+                    .context("failed to configure client certificate for TLS")?
+            }
+            _ => builder.with_no_client_auth(),
+        };
+
+        Ok(config)
+    }
+
+    /// Builds a rustls `ServerConfig` presenting this service's own
+    /// certificate/key as its identity, for use by a server accepting
+    /// connections directly over rustls (the actix-web SSE/HTTP relay).
+    /// Verifies client certificates against `ca_cert_path` when
+    /// `require_client_auth` is set; otherwise any client is accepted, same
+    /// as a plain TLS listener.
+    pub fn to_rustls_server_config(&self) -> Result<rustls::ServerConfig> {
+        let cert_path = self
+            .client_cert_path
+            .as_deref()
+            .context("tls.client_cert_path is required for a server identity")?;
+        let key_path = self
+            .client_key_path
+            .as_deref()
+            .context("tls.client_key_path is required for a server identity")?;
+        let certs = load_certs(cert_path)?;
+        let key = load_private_key(key_path)?;
+
+        let builder = rustls::ServerConfig::builder();
+        let config = if self.require_client_auth {
+            let mut roots = rustls::RootCertStore::empty();
+            for cert in load_certs(&self.ca_cert_path)? {
+                roots
+                    .add(cert)
+                    .context("failed to add CA certificate to root store")?;
+            }
+            let verifier = rustls::server::WebPkiClientVerifier::builder(Arc::new(roots))
+                .build()
+                .context("failed to build client certificate verifier")?;
+            builder.with_client_cert_verifier(verifier)
+        } else {
+            builder.with_no_client_auth()
+        }
+        .with_single_cert(certs, key)
+        .context("failed to configure server certificate/key")?;
+
+        Ok(config)
+    }
+
+    /// Builds a tonic `ClientTlsConfig` for dialing a gRPC peer: trusts
+    /// `ca_cert_path` and presents this service's certificate/key if one is
+    /// configured.
+    pub fn to_tonic_client_config(&self) -> Result<tonic::transport::ClientTlsConfig> {
+        let ca_pem = fs::read(&self.ca_cert_path)
+            .with_context(|| format!("failed to read CA bundle {}", self.ca_cert_path))?;
+        let mut config = tonic::transport::ClientTlsConfig::new()
+            .ca_certificate(tonic::transport::Certificate::from_pem(ca_pem));
+
+        if let Some(identity) = self.tonic_identity()? {
+            config = config.identity(identity);
+        }
+
+        Ok(config)
+    }
+
+    /// Builds a tonic `ServerTlsConfig` presenting this service's own
+    /// certificate/key as its server identity, optionally requiring and
+    /// verifying client certificates against `ca_cert_path` when
+    /// `require_client_auth` is set.
+    pub fn to_tonic_server_config(&self) -> Result<tonic::transport::ServerTlsConfig> {
+        let identity = self
+            .tonic_identity()?
+            .context("tls.client_cert_path/client_key_path are required for a server identity")?;
+        let mut config = tonic::transport::ServerTlsConfig::new().identity(identity);
+
+        if self.require_client_auth {
+            let ca_pem = fs::read(&self.ca_cert_path)
+                .with_context(|| format!("failed to read CA bundle {}", self.ca_cert_path))?;
+            config = config.client_ca_root(tonic::transport::Certificate::from_pem(ca_pem));
+        }
+
+        Ok(config)
+    }
+
+    fn tonic_identity(&self) -> Result<Option<tonic::transport::Identity>> {
+        match (&self.client_cert_path, &self.client_key_path) {
+            (Some(cert_path), Some(key_path)) => {
+                let cert_pem = fs::read(cert_path)
+                    .with_context(|| format!("failed to read certificate file {}", cert_path))?;
+                let key_pem = fs::read(key_path)
+                    .with_context(|| format!("failed to read private key file {}", key_path))?;
+                Ok(Some(tonic::transport::Identity::from_pem(cert_pem, key_pem)))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+fn load_certs(path: impl AsRef<Path>) -> Result<Vec<rustls::pki_types::CertificateDer<'static>>> {
+    let path = path.as_ref();
+    let pem = fs::read(path)
+        .with_context(|| format!("failed to read certificate file {}", path.display()))?;
+    rustls_pemfile::certs(&mut pem.as_slice())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("failed to parse certificate(s) in {}", path.display()))
+}
+
+fn load_private_key(path: impl AsRef<Path>) -> Result<rustls::pki_types::PrivateKeyDer<'static>> {
+    let path = path.as_ref();
+    let pem = fs::read(path)
+        .with_context(|| format!("failed to read private key file {}", path.display()))?;
+    rustls_pemfile::private_key(&mut pem.as_slice())
+        .with_context(|| format!("failed to parse private key in {}", path.display()))?
+        .ok_or_else(|| anyhow::anyhow!("no private key found in {}", path.display()))
+}
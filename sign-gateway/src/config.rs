@@ -1,16 +1,41 @@
 use std::path::Path;
 use std::fs;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::{Context, Result};
 use log::info;
 use serde::{Deserialize, Serialize};
 
+use participant::{ProxyConfig, TlsConfig};
 use sse::{AppConfig as SseAppConfig, SSEConfig};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SignGatewayConfig {
     pub server: ServerConfig,
     pub logging: LoggingConfig,
+    /// The upstream sign-service(s) this gateway proxies to; see
+    /// `crate::grpc::SignGatewayGrpc`.
+    pub sign_service: SignServiceUpstreamConfig,
+    /// Mutual TLS for the SSE relay's listener and for the gRPC link to the
+    /// upstream sign-service(s). Omit to run both unencrypted, as before.
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// Routes the gRPC link to the upstream sign-service(s) through a SOCKS5
+    /// proxy (e.g. a local Tor daemon) instead of dialing it directly, so
+    /// the sign-service can live behind a `.onion` address. Omit to dial
+    /// directly, as before.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignServiceUpstreamConfig {
+    /// One or more upstream sign-service endpoints. A single entry behaves
+    /// exactly like the old single-upstream gateway; more than one gets
+    /// round-robin dispatch with per-endpoint health-checking and failover
+    /// (see `crate::grpc::SignGatewayGrpc::new_with_proxy`).
+    pub urls: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -18,6 +43,23 @@ pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub cors_origins: Vec<String>,
+    /// Maximum number of past broadcast messages each room keeps for replay;
+    /// see `sse::SSEConfig::history_capacity`.
+    #[serde(default = "default_history_capacity")]
+    pub history_capacity: usize,
+    /// How long a graceful shutdown waits for in-flight SSE sessions to
+    /// finish on their own, after it stops admitting new ones, before
+    /// force-closing the listener; see `SseServer::shutdown_after_drain`.
+    #[serde(default = "default_shutdown_grace_period_secs")]
+    pub shutdown_grace_period_secs: u64,
+}
+
+fn default_history_capacity() -> usize {
+    sse::DEFAULT_ROOM_HISTORY_CAPACITY
+}
+
+fn default_shutdown_grace_period_secs() -> u64 {
+    30
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -35,33 +77,101 @@ impl SignGatewayConfig {
             .with_context(|| format!("Failed to parse YAML config file: {}", path.as_ref().display()))
     }
 
+    /// Grace period for [`sse::SseServer::shutdown_after_drain`].
+    pub fn shutdown_grace_period(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.server.shutdown_grace_period_secs)
+    }
+
     pub fn to_sse_config(&self) -> SseAppConfig {
         SseAppConfig {
             sse: SSEConfig {
                 host: self.server.host.clone(),
                 port: self.server.port,
+                history_capacity: self.server.history_capacity,
+                cors_origins: self.server.cors_origins.clone(),
             },
         }
     }
 }
 
-pub fn setup_logging(config: &LoggingConfig) -> Result<()> {
-    let log_level = match config.level.to_lowercase().as_str() {
+pub(crate) fn parse_log_level(level: &str) -> log::LevelFilter {
+    match level.to_lowercase().as_str() {
         "error" => log::LevelFilter::Error,
         "warn" => log::LevelFilter::Warn,
         "info" => log::LevelFilter::Info,
         "debug" => log::LevelFilter::Debug,
         "trace" => log::LevelFilter::Trace,
         _ => {
-            eprintln!("Warning: Unknown log level '{}', using 'info'", config.level);
+            eprintln!("Warning: Unknown log level '{}', using 'info'", level);
             log::LevelFilter::Info
         }
-    };
+    }
+}
+
+fn level_from_usize(level: usize) -> log::LevelFilter {
+    match level {
+        0 => log::LevelFilter::Off,
+        1 => log::LevelFilter::Error,
+        2 => log::LevelFilter::Warn,
+        3 => log::LevelFilter::Info,
+        4 => log::LevelFilter::Debug,
+        _ => log::LevelFilter::Trace,
+    }
+}
+
+/// Lets a caller retune the active log level after [`setup_logging`] has
+/// run, e.g. from a `SIGHUP` config reload. `env_logger` only supports a
+/// one-shot `init()`, so this wraps its logger in [`ReloadableLogger`]
+/// instead of re-initializing it.
+#[derive(Clone)]
+pub struct LogHandle {
+    level: Arc<AtomicUsize>,
+}
+
+impl LogHandle {
+    pub fn set_level(&self, level: log::LevelFilter) {
+        self.level.store(level as usize, Ordering::Relaxed);
+        log::set_max_level(level);
+        info!("Log level changed to: {}", level);
+    }
+}
+
+/// Delegates formatting/output to a permissively-configured `env_logger`
+/// logger, but gates every record on `level` first, so [`LogHandle::set_level`]
+/// can change the effective level without rebuilding the inner logger.
+struct ReloadableLogger {
+    inner: env_logger::Logger,
+    level: Arc<AtomicUsize>,
+}
+
+impl log::Log for ReloadableLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= level_from_usize(self.level.load(Ordering::Relaxed)) && self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+pub fn setup_logging(config: &LoggingConfig) -> Result<LogHandle> {
+    let log_level = parse_log_level(&config.level);
+    let level = Arc::new(AtomicUsize::new(log_level as usize));
+
+    let inner = env_logger::Builder::from_default_env()
+        .filter_level(log::LevelFilter::Trace)
+        .build();
 
-    env_logger::Builder::from_default_env()
-        .filter_level(log_level)
-        .init();
+    log::set_boxed_logger(Box::new(ReloadableLogger { inner, level: level.clone() }))
+        .map_err(|e| anyhow::anyhow!("Failed to install logger: {}", e))?;
+    log::set_max_level(log_level);
 
     info!("Logging initialized with level: {}", config.level);
-    Ok(())
+    Ok(LogHandle { level })
 }
@@ -1,34 +1,173 @@
 use std::future::Future;
 use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Context;
-use log::{error, info};
+use log::{error, info, warn};
+use participant::{ProxyConfig, TlsConfig};
 use proto::mpc::participant_client::ParticipantClient;
 use proto::mpc::participant_server::{Participant, ParticipantServer};
 use proto::mpc::sign_gateway_server::{SignGateway, SignGatewayServer};
 use proto::mpc::{SignMessage, SignatureMessage};
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::sync::RwLock;
 use tonic::transport::{Channel, Server};
 use tonic::{Request, Response, Status};
 
+/// How often the background task re-probes upstreams currently marked
+/// unhealthy, trying to bring them back into rotation.
+const HEALTH_CHECK_INTERVAL_SECS: u64 = 15;
+
+/// One upstream sign-service this gateway can proxy `sign_tx` to. `client` is
+/// `None` while the endpoint is unreachable; [`run_health_checker`] is the
+/// only thing that re-dials it back to `Some`.
+struct UpstreamEntry {
+    endpoint: String,
+    client: Option<ParticipantClient<Channel>>,
+    healthy: bool,
+}
+
 #[derive(Clone)]
 pub struct SignGatewayGrpc {
-    upstream: Arc<Mutex<ParticipantClient<Channel>>>,
+    /// The pool of upstream sign-services this gateway proxies to, dispatched
+    /// round-robin over the currently-healthy ones; see [`Self::pick_client`].
+    upstreams: Arc<RwLock<Vec<UpstreamEntry>>>,
+    /// Round-robin cursor into `upstreams`, shared across clones so requests
+    /// handled by different tonic service instances still rotate together.
+    next: Arc<AtomicUsize>,
 }
 
 impl SignGatewayGrpc {
-    pub async fn new(upstream_endpoint: &str) -> Result<Self, GatewayError> {
-        let client = ParticipantClient::connect(upstream_endpoint.to_string())
-            .await
-            .context("failed to connect to sign-service")?;
+    /// Same as [`Self::new_with_proxy`], dialing `upstream_endpoints`
+    /// directly with no TLS or proxy.
+    pub async fn new(upstream_endpoints: &[String]) -> Result<Self, GatewayError> {
+        Self::new_with_proxy(upstream_endpoints, None, None).await
+    }
+
+    /// Same as [`Self::new`], but dials every upstream sign-service over
+    /// mutual TLS using `tls` instead of a plain connection.
+    pub async fn new_with_tls(
+        upstream_endpoints: &[String],
+        tls: Option<&TlsConfig>,
+    ) -> Result<Self, GatewayError> {
+        Self::new_with_proxy(upstream_endpoints, tls, None).await
+    }
+
+    /// Builds a gateway that proxies `sign_tx` to whichever of
+    /// `upstream_endpoints` is currently healthy, round-robin over the
+    /// healthy set. An endpoint that fails to dial at startup, or whose
+    /// connection later errors on a proxied call, is marked unhealthy and
+    /// skipped until a background task (spawned here, running for the
+    /// process's lifetime) successfully re-dials it. At least one endpoint
+    /// must be given, e.g. a single-element slice for the old single-upstream
+    /// behavior.
+    ///
+    /// Optionally routes every dial through a SOCKS5 proxy (e.g. Tor) instead
+    /// of connecting directly, optionally also securing it with `tls`; see
+    /// [`ProxyConfig`]. Combining both on the same dial isn't supported yet
+    /// (see [`ProxyConfig::connect_channel`]), so `tls` is ignored when
+    /// `proxy` is set.
+    pub async fn new_with_proxy(
+        upstream_endpoints: &[String],
+        tls: Option<&TlsConfig>,
+        proxy: Option<&ProxyConfig>,
+    ) -> Result<Self, GatewayError> {
+        if upstream_endpoints.is_empty() {
+            return Err(anyhow::anyhow!("at least one upstream sign-service endpoint is required").into());
+        }
+
+        let mut entries = Vec::with_capacity(upstream_endpoints.len());
+        for endpoint in upstream_endpoints {
+            match dial(endpoint, tls, proxy).await {
+                Ok(client) => {
+                    info!("Connected to upstream sign-service at {}", endpoint);
+                    entries.push(UpstreamEntry {
+                        endpoint: endpoint.clone(),
+                        client: Some(client),
+                        healthy: true,
+                    });
+                }
+                Err(e) => {
+                    error!("Upstream sign-service {} unreachable at startup, starting unhealthy: {:#}", endpoint, e);
+                    entries.push(UpstreamEntry {
+                        endpoint: endpoint.clone(),
+                        client: None,
+                        healthy: false,
+                    });
+                }
+            }
+        }
+
+        let upstreams = Arc::new(RwLock::new(entries));
+
+        tokio::spawn(run_health_checker(upstreams.clone(), tls.cloned(), proxy.cloned()));
+
         Ok(Self {
-            upstream: Arc::new(Mutex::new(client)),
+            upstreams,
+            next: Arc::new(AtomicUsize::new(0)),
         })
     }
 
-    pub async fn serve<F>(self, addr: &str, shutdown: F) -> Result<(), GatewayError>
+    /// Proxies `payload` to the next healthy upstream in round-robin order.
+    ///
+    /// Only a transport-layer failure (`Status::unavailable` - dial/connection
+    /// errors, the upstream process being down, etc.) marks that upstream
+    /// unhealthy and retries against the rest of the healthy set; an
+    /// application-level rejection (`invalid_argument`, `permission_denied`,
+    /// `unauthenticated`, `not_found`, ...) means the upstream is working
+    /// fine and rejected this specific request, so it's returned straight to
+    /// the caller without touching upstream health or retrying - otherwise a
+    /// single malformed/unauthorized request would get replayed against
+    /// every upstream in the pool and mark all of them unhealthy at once.
+    async fn proxy_sign_tx(&self, payload: SignMessage) -> Result<Response<SignatureMessage>, Status> {
+        let len = self.upstreams.read().await.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        for offset in 0..len {
+            let index = (start + offset) % len;
+
+            let mut client = {
+                let upstreams = self.upstreams.read().await;
+                let entry = &upstreams[index];
+                match (&entry.client, entry.healthy) {
+                    (Some(client), true) => client.clone(),
+                    _ => continue,
+                }
+            };
+
+            match client.sign_tx(Request::new(payload.clone())).await {
+                Ok(response) => return Ok(response),
+                Err(status) if status.code() == tonic::Code::Unavailable => {
+                    let mut upstreams = self.upstreams.write().await;
+                    warn!(
+                        "Upstream sign-service {} unreachable ({}), marking unhealthy",
+                        upstreams[index].endpoint, status.message()
+                    );
+                    upstreams[index].healthy = false;
+                    upstreams[index].client = None;
+                }
+                Err(status) => {
+                    warn!(
+                        "Upstream sign-service {} rejected request for tx_id {} ({}), not a transport failure",
+                        self.upstreams.read().await[index].endpoint, payload.tx_id, status.message()
+                    );
+                    return Err(status);
+                }
+            }
+        }
+
+        error!("No healthy upstream sign-service available for tx_id: {}", payload.tx_id);
+        Err(Status::unavailable("no healthy upstream sign-service available"))
+    }
+
+    pub async fn serve<F>(
+        self,
+        addr: &str,
+        tls: Option<&TlsConfig>,
+        shutdown: F,
+    ) -> Result<(), GatewayError>
     where
         F: Future<Output = ()> + Send + 'static,
     {
@@ -39,7 +178,17 @@ impl SignGatewayGrpc {
         info!("  - Exposing Participant service (for client compatibility)");
         info!("  - Exposing SignGateway service");
 
-        Server::builder()
+        let mut builder = Server::builder();
+        if let Some(tls) = tls {
+            builder = builder
+                .tls_config(
+                    tls.to_tonic_server_config()
+                        .context("failed to configure gRPC server TLS")?,
+                )
+                .context("failed to apply TLS config to gRPC server")?;
+        }
+
+        builder
             .add_service(ParticipantServer::new(self.clone()))
             .add_service(SignGatewayServer::new(self))
             .serve_with_shutdown(addr, shutdown)
@@ -49,6 +198,75 @@ impl SignGatewayGrpc {
     }
 }
 
+/// Connects to a single upstream sign-service endpoint, applying `tls`
+/// and/or `proxy` the same way every other dial site in this codebase does.
+async fn dial(
+    endpoint: &str,
+    tls: Option<&TlsConfig>,
+    proxy: Option<&ProxyConfig>,
+) -> anyhow::Result<ParticipantClient<Channel>> {
+    let channel_endpoint = Channel::from_shared(endpoint.to_string())
+        .context("invalid upstream endpoint")?;
+    let channel_endpoint = match tls {
+        Some(tls) => channel_endpoint
+            .tls_config(
+                tls.to_tonic_client_config()
+                    .context("failed to configure upstream TLS")?,
+            )
+            .context("failed to apply TLS config to upstream endpoint")?,
+        None => channel_endpoint,
+    };
+    let channel = match proxy {
+        Some(proxy) => proxy
+            .connect_channel(channel_endpoint)
+            .await
+            .context("failed to connect to sign-service through SOCKS5 proxy")?,
+        None => channel_endpoint
+            .connect()
+            .await
+            .context("failed to connect to sign-service")?,
+    };
+    Ok(ParticipantClient::new(channel))
+}
+
+/// Runs for the lifetime of the gateway process, periodically re-dialing
+/// every upstream currently marked unhealthy and bringing it back into
+/// rotation on success.
+async fn run_health_checker(
+    upstreams: Arc<RwLock<Vec<UpstreamEntry>>>,
+    tls: Option<TlsConfig>,
+    proxy: Option<ProxyConfig>,
+) {
+    let mut interval = tokio::time::interval(Duration::from_secs(HEALTH_CHECK_INTERVAL_SECS));
+    loop {
+        interval.tick().await;
+
+        let unhealthy: Vec<(usize, String)> = {
+            let upstreams = upstreams.read().await;
+            upstreams
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| !entry.healthy)
+                .map(|(index, entry)| (index, entry.endpoint.clone()))
+                .collect()
+        };
+
+        for (index, endpoint) in unhealthy {
+            match dial(&endpoint, tls.as_ref(), proxy.as_ref()).await {
+                Ok(client) => {
+                    info!("Upstream sign-service {} is healthy again", endpoint);
+                    let mut upstreams = upstreams.write().await;
+                    upstreams[index].client = Some(client);
+                    upstreams[index].healthy = true;
+                }
+                Err(e) => {
+                    warn!("Upstream sign-service {} still unreachable: {:#}", endpoint, e);
+                }
+            }
+        }
+    }
+}
+
 #[tonic::async_trait]
 impl Participant for SignGatewayGrpc {
     async fn sign_tx(
@@ -60,14 +278,7 @@ impl Participant for SignGatewayGrpc {
             "[Participant] Proxying SignTx - tx_id: {} account_id: {}",
             payload.tx_id, payload.account_id
         );
-        let mut client = self.upstream.lock().await;
-        client
-            .sign_tx(Request::new(payload))
-            .await
-            .map_err(|status| {
-                error!("Upstream SignTx failed: {}", status.message());
-                Status::unavailable("upstream sign-service unavailable")
-            })
+        self.proxy_sign_tx(payload).await
     }
 }
 
@@ -82,14 +293,7 @@ impl SignGateway for SignGatewayGrpc {
             "[SignGateway] Proxying SignTx - tx_id: {} account_id: {}",
             payload.tx_id, payload.account_id
         );
-        let mut client = self.upstream.lock().await;
-        client
-            .sign_tx(Request::new(payload))
-            .await
-            .map_err(|status| {
-                error!("Upstream SignTx failed: {}", status.message());
-                Status::unavailable("upstream sign-service unavailable")
-            })
+        self.proxy_sign_tx(payload).await
     }
 }
 
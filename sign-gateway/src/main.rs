@@ -7,7 +7,7 @@ use tokio::signal;
 use tokio::task::JoinHandle;
 
 use sse::SseServer;
-use crate::config::{SignGatewayConfig, setup_logging};
+use crate::config::{SignGatewayConfig, parse_log_level, setup_logging};
 use crate::grpc::SignGatewayGrpc;
 
 #[tokio::main]
@@ -21,24 +21,35 @@ async fn main() -> Result<()> {
     let config = SignGatewayConfig::load_from_file(&config_path)
         .context("Failed to load configuration")?;
 
-    // Set up logging
-    setup_logging(&config.logging)
+    // Set up logging; keep the handle so a SIGHUP reload below can retune
+    // the level without a full restart.
+    let log_handle = setup_logging(&config.logging)
         .context("Failed to setup logging")?;
 
     info!("Sign Gateway starting up...");
     info!("Configuration loaded from: {}", config_path);
     info!("Server will start on: {}:{}", config.server.host, config.server.port);
 
-    // Create SSE server
+    // Create SSE server, serving over mutual TLS if configured
     let sse_config = config.to_sse_config();
-    let sse_server = SseServer::new(sse_config);
+    let sse_server = match &config.tls {
+        Some(tls) => {
+            info!("Mutual TLS configured; securing SSE relay and gRPC gateway");
+            SseServer::new(sse_config).with_tls(
+                tls.to_rustls_server_config()
+                    .context("Failed to configure SSE server TLS")?,
+            )
+        }
+        None => SseServer::new(sse_config),
+    };
     info!("SSE Server created - {}:{}", config.server.host, config.server.port);
 
-    let grpc_service = SignGatewayGrpc::new(&config.sign_service.url)
+    let grpc_service = SignGatewayGrpc::new_with_proxy(&config.sign_service.urls, config.tls.as_ref(), config.proxy.as_ref())
         .await
         .context("Failed to initialize gRPC gateway")?;
 
     let grpc_addr = config.grpc_addr();
+    let grpc_tls = config.tls.clone();
 
     // Shared shutdown trigger
     let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel::<bool>(false);
@@ -59,57 +70,99 @@ async fn main() -> Result<()> {
             let _ = shutdown_rx_grpc.changed().await;
         };
         grpc_service
-            .serve(&grpc_addr, shutdown)
+            .serve(&grpc_addr, grpc_tls.as_ref(), shutdown)
             .await
             .context("gRPC server failed")
     });
 
     let mut server_task = Some(server_task);
     let mut grpc_task = Some(grpc_task);
-
-    // Wait for shutdown signal (Ctrl+C or SIGTERM)
-    tokio::select! {
-        _ = signal::ctrl_c() => {
-            info!("Received Ctrl+C signal, initiating graceful shutdown...");
-        }
-        _ = async {
-            #[cfg(unix)]
-            {
-                let mut sigterm = signal::unix::signal(signal::unix::SignalKind::terminate())
-                    .expect("Failed to setup SIGTERM handler");
-                sigterm.recv().await
+    let mut listen_addr = format!("{}:{}", config.server.host, config.server.port);
+
+    #[cfg(unix)]
+    let mut sigterm = signal::unix::signal(signal::unix::SignalKind::terminate())
+        .expect("Failed to setup SIGTERM handler");
+    #[cfg(unix)]
+    let mut sighup = signal::unix::signal(signal::unix::SignalKind::hangup())
+        .expect("Failed to setup SIGHUP handler");
+
+    // Wait for a shutdown signal (Ctrl+C or SIGTERM), reloading config live
+    // on SIGHUP instead of exiting the loop.
+    loop {
+        tokio::select! {
+            _ = signal::ctrl_c() => {
+                info!("Received Ctrl+C signal, initiating graceful shutdown...");
+                break;
             }
-            #[cfg(not(unix))]
-            {
-                std::future::pending::<()>().await
+            _ = async {
+                #[cfg(unix)]
+                { sigterm.recv().await; }
+                #[cfg(not(unix))]
+                { std::future::pending::<()>().await }
+            } => {
+                info!("Received SIGTERM signal, initiating graceful shutdown...");
+                break;
             }
-        } => {
-            info!("Received SIGTERM signal, initiating graceful shutdown...");
-        }
-        _ = async {
-            if let Some(task) = &mut server_task {
-                let _ = task.await;
+            _ = async {
+                #[cfg(unix)]
+                { sighup.recv().await; }
+                #[cfg(not(unix))]
+                { std::future::pending::<()>().await }
+            } => {
+                info!("Received SIGHUP signal, reloading configuration from {}...", config_path);
+                match SignGatewayConfig::load_from_file(&config_path) {
+                    Ok(new_config) => {
+                        log_handle.set_level(parse_log_level(&new_config.logging.level));
+                        sse_server.set_cors_origins(new_config.server.cors_origins.clone());
+                        info!(
+                            "Reloaded logging level ({}) and {} CORS origin(s)",
+                            new_config.logging.level,
+                            new_config.server.cors_origins.len()
+                        );
+
+                        let new_listen_addr = format!("{}:{}", new_config.server.host, new_config.server.port);
+                        if new_listen_addr != listen_addr {
+                            log::warn!(
+                                "Config reload: listener address changed from {} to {}, but this requires a restart to take effect; still listening on {}",
+                                listen_addr, new_listen_addr, listen_addr
+                            );
+                        }
+                        listen_addr = new_listen_addr;
+                    }
+                    Err(e) => {
+                        log::error!("Failed to reload configuration from {}: {}", config_path, e);
+                    }
+                }
             }
-        } => {
-            info!("SSE server exited");
-            server_task = None;
-        }
-        _ = async {
-            if let Some(task) = &mut grpc_task {
-                let _ = task.await;
+            _ = async {
+                if let Some(task) = &mut server_task {
+                    let _ = task.await;
+                }
+            }, if server_task.is_some() => {
+                info!("SSE server exited");
+                server_task = None;
+                break;
+            }
+            _ = async {
+                if let Some(task) = &mut grpc_task {
+                    let _ = task.await;
+                }
+            }, if grpc_task.is_some() => {
+                info!("gRPC server exited");
+                grpc_task = None;
+                break;
             }
-        } => {
-            info!("gRPC server exited");
-            grpc_task = None;
         }
     }
 
     // Notify background tasks to stop
     let _ = shutdown_tx.send(true);
 
-    // Gracefully shutdown the server
+    // Gracefully shutdown the server, draining in-flight room sessions
+    // before forcing the listener closed so a signing round isn't cut off
+    // mid-way.
     info!("Shutting down SSE server...");
-    if let Err(e) = sse_server.shutdown().await {
+    if let Err(e) = sse_server.shutdown_after_drain(config.shutdown_grace_period()).await {
         log::error!("Error shutting down SSE server: {}", e);
     }
 
@@ -5,6 +5,7 @@ use anyhow::{Context, Result};
 use log::info;
 use serde::{Deserialize, Serialize};
 
+use participant::{ProxyConfig, ReconnectPolicy, TlsConfig, TransportMode};
 use sse::{AppConfig as SseAppConfig, SSEConfig};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -12,6 +13,36 @@ pub struct SignServiceConfig {
     pub server: ServerConfig,
     pub logging: LoggingConfig,
     pub mpc: MpcConfig,
+    /// Mutual TLS for this participant's SSE/HTTP transport to the relay and
+    /// its own gRPC endpoint. Omit to run both unencrypted, as before.
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+    /// How aggressively to reconnect a dropped SSE subscription to the
+    /// relay. Omit to keep `ReconnectPolicy::default()`.
+    #[serde(default)]
+    pub reconnect: Option<ReconnectPolicy>,
+    /// Routes the SSE/HTTP transport to the relay through a SOCKS5 proxy
+    /// (e.g. a local Tor daemon) instead of dialing it directly, so the
+    /// gateway/relay can live behind a `.onion` address. Omit to dial
+    /// directly, as before.
+    #[serde(default)]
+    pub proxy: Option<ProxyConfig>,
+    /// Directory to scope this instance's single-instance lock file under
+    /// (see `crate::lock::InstanceLock`), so operators running many accounts
+    /// on one host can keep each account's lock separate. Omit to place the
+    /// lock alongside `mpc.key_share_file` instead.
+    #[serde(default)]
+    pub data_dir: Option<String>,
+    /// How outgoing protocol messages reach the relay. Omit to keep the
+    /// default one-POST-per-message behavior.
+    #[serde(default)]
+    pub transport: TransportConfig,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct TransportConfig {
+    #[serde(default)]
+    pub mode: TransportMode,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -25,6 +56,14 @@ pub struct SseServerConfig {
     pub host: String,
     pub port: u16,
     pub cors_origins: Vec<String>,
+    /// Maximum number of past broadcast messages each room keeps for replay;
+    /// see `sse::SSEConfig::history_capacity`.
+    #[serde(default = "default_history_capacity")]
+    pub history_capacity: usize,
+}
+
+fn default_history_capacity() -> usize {
+    sse::DEFAULT_ROOM_HISTORY_CAPACITY
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -45,6 +84,58 @@ pub struct MpcConfig {
     pub threshold: u16,
     pub total_participants: u16,
     pub key_share_file: String,
+    /// How this participant obtains its key shares. Defaults to
+    /// `trusted-dealer-file` so existing deployments that don't set this
+    /// field keep reading pre-dealt shares from `key_share_file`.
+    #[serde(default)]
+    pub provisioning: KeyProvisioningMode,
+    /// Whether `key_share_file` is plaintext JSON or an age-encrypted
+    /// envelope. Defaults to `plaintext` so existing deployments need no
+    /// config change; see `crate::service::load_key_shares`, which also
+    /// auto-detects an age envelope by its magic header regardless of this
+    /// setting, so a file can be re-encrypted without flipping it first.
+    #[serde(default)]
+    pub key_share_encryption: KeyShareEncryptionMode,
+    /// Account id to generate a fresh key for; required when `provisioning`
+    /// is `dkg`, unused otherwise.
+    #[serde(default)]
+    pub dkg_account_id: Option<String>,
+    /// Execution id every party in the new key must agree on; required when
+    /// `provisioning` is `dkg`, unused otherwise.
+    #[serde(default)]
+    pub dkg_execution_id: Option<String>,
+}
+
+/// Selects how a participant's key shares come into existence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeyProvisioningMode {
+    /// Read pre-dealt shares produced by `key-gen`'s trusted dealer from
+    /// `key_share_file`.
+    #[default]
+    TrustedDealerFile,
+    /// Run distributed key generation with the other participants over the
+    /// gateway transport instead, writing the resulting share to
+    /// `key_share_file` (see `participant::run_keygen`).
+    Dkg,
+}
+
+/// Selects how `mpc.key_share_file` is protected at rest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case", tag = "mode")]
+pub enum KeyShareEncryptionMode {
+    /// Read `key_share_file` as plaintext `serde_json`, as before.
+    #[default]
+    Plaintext,
+    /// Read `key_share_file` as an age-encrypted envelope (see the `age`
+    /// crate), deriving the decryption key from a passphrase via age's
+    /// built-in scrypt-based passphrase recipient. The passphrase itself is
+    /// never stored in this config; it's read at load time from the
+    /// environment variable named here (or a KMS-issued secret standing in
+    /// for one).
+    Encrypted {
+        passphrase_env: String,
+    },
 }
 
 impl SignServiceConfig {
@@ -61,6 +152,8 @@ impl SignServiceConfig {
             sse: SSEConfig {
                 host: self.server.sse.host.clone(),
                 port: self.server.sse.port,
+                history_capacity: self.server.sse.history_capacity,
+                cors_origins: self.server.sse.cors_origins.clone(),
             },
         }
     }
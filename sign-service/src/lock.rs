@@ -0,0 +1,127 @@
+use std::path::{Path, PathBuf};
+use std::{fs, process};
+
+use anyhow::{Context, Result};
+use log::{info, warn};
+use thiserror::Error;
+
+/// Advisory single-instance guard keyed on a participant's key share file, so
+/// two sign-service processes can never drive independent MPC sessions off
+/// the same share. Held as a `<key_share_file>.lock` file (or, when
+/// `data_dir` is configured, `<data_dir>/<key_share_file_name>.lock`)
+/// containing the holding process's PID and listen address; a lock whose PID
+/// is no longer alive is treated as stale and silently reclaimed.
+pub struct InstanceLock {
+    path: PathBuf,
+}
+
+#[derive(Debug, Error)]
+pub enum LockError {
+    #[error(
+        "sign-service is already running for key share file {key_share_file} (pid {pid}, listening on {listen_addr}); lock file: {lock_path}"
+    )]
+    AlreadyRunning {
+        key_share_file: String,
+        pid: u32,
+        listen_addr: String,
+        lock_path: String,
+    },
+}
+
+impl InstanceLock {
+    /// Acquires the lock for `key_share_file`, scoping the lock file under
+    /// `data_dir` when given so operators running many accounts can keep
+    /// each one's lock separate. Fails with [`LockError::AlreadyRunning`] if
+    /// a live process already holds it; reclaims the lock if the recorded
+    /// PID is dead.
+    pub fn acquire(
+        data_dir: Option<&str>,
+        key_share_file: &str,
+        listen_addr: &str,
+    ) -> Result<Self> {
+        let path = lock_path(data_dir, key_share_file)?;
+
+        if let Some(held) = read_lock(&path)? {
+            if is_process_alive(held.pid) {
+                return Err(LockError::AlreadyRunning {
+                    key_share_file: key_share_file.to_string(),
+                    pid: held.pid,
+                    listen_addr: held.listen_addr,
+                    lock_path: path.display().to_string(),
+                }
+                .into());
+            }
+            warn!(
+                "Reclaiming stale lock {} held by dead pid {}",
+                path.display(),
+                held.pid
+            );
+        }
+
+        fs::write(&path, format!("{}\n{}\n", process::id(), listen_addr))
+            .with_context(|| format!("Failed to write lock file {}", path.display()))?;
+
+        info!("Acquired single-instance lock at {}", path.display());
+        Ok(Self { path })
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        match fs::remove_file(&self.path) {
+            Ok(()) => info!("Released single-instance lock at {}", self.path.display()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Failed to remove lock file {}: {}", self.path.display(), e),
+        }
+    }
+}
+
+struct HeldLock {
+    pid: u32,
+    listen_addr: String,
+}
+
+fn lock_path(data_dir: Option<&str>, key_share_file: &str) -> Result<PathBuf> {
+    match data_dir {
+        Some(dir) => {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create data dir {}", dir))?;
+            let file_name = Path::new(key_share_file)
+                .file_name()
+                .context("key_share_file has no file name component")?;
+            let mut lock_name = file_name.to_os_string();
+            lock_name.push(".lock");
+            Ok(Path::new(dir).join(lock_name))
+        }
+        None => Ok(PathBuf::from(format!("{}.lock", key_share_file))),
+    }
+}
+
+fn read_lock(path: &Path) -> Result<Option<HeldLock>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            let mut lines = contents.lines();
+            let pid = lines.next().and_then(|line| line.trim().parse::<u32>().ok());
+            let listen_addr = lines.next().unwrap_or_default().trim().to_string();
+            Ok(pid.map(|pid| HeldLock { pid, listen_addr }))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => {
+            Err(e).with_context(|| format!("Failed to read lock file {}", path.display()))
+        }
+    }
+}
+
+/// Checks whether `pid` still belongs to a live process. Only implemented
+/// precisely on Linux (via `/proc`, so no extra dependency is needed);
+/// elsewhere we conservatively assume it's alive rather than risk reclaiming
+/// a lock out from under a running process.
+#[cfg(target_os = "linux")]
+fn is_process_alive(pid: u32) -> bool {
+    Path::new(&format!("/proc/{}", pid)).exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_process_alive(_pid: u32) -> bool {
+    true
+}
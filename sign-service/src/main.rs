@@ -1,4 +1,5 @@
 mod config;
+mod lock;
 mod service;
 
 use anyhow::{Context, Result};
@@ -1,35 +1,96 @@
 use std::env;
 use std::fs;
+use std::io::Read;
 use std::collections::HashMap;
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use log::{info, error};
 use tokio::signal;
+use zeroize::Zeroizing;
 
-use participant::ParticipantServer;
+use participant::{Client, ParticipantServer, RequesterPolicy};
 use cggmp21::KeyShare;
 use cggmp21::security_level::SecurityLevel128;
 use cggmp21::supported_curves::Secp256k1;
 
-use crate::config::SignServiceConfig;
+use crate::config::{KeyProvisioningMode, KeyShareEncryptionMode, SignServiceConfig};
+use crate::lock::InstanceLock;
 
 /// Key share file environment variable name
 const KEY_SHARE_FILE_ENV: &str = "SIGN_SERVICE_KEY_SHARE_FILE";
 
-/// Load key shares from configured file path
-pub fn load_key_shares(key_share_file: &str) -> Result<HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>> {
+/// Header every age-encrypted file starts with; used to auto-detect an
+/// encrypted `key_share_file` without relying on `mpc.key_share_encryption`
+/// being set correctly.
+const AGE_MAGIC: &[u8] = b"age-encryption.org/v1";
+
+/// Load key shares from the configured file path. Transparently decrypts an
+/// age-encrypted envelope (detected by its magic header) using the
+/// passphrase named by `encryption`; a plain JSON file is read as before.
+pub fn load_key_shares(
+    key_share_file: &str,
+    encryption: &KeyShareEncryptionMode,
+) -> Result<HashMap<String, KeyShare<Secp256k1, SecurityLevel128>>> {
     info!("Loading key shares from file: {}", key_share_file);
-    let key_share_json = fs::read_to_string(key_share_file)
+    let raw = fs::read(key_share_file)
         .with_context(|| format!("Failed to read key share file {}", key_share_file))?;
 
-    let key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>> = serde_json::from_str(&key_share_json)
+    let key_share_json: Zeroizing<Vec<u8>> = if raw.starts_with(AGE_MAGIC) {
+        decrypt_key_share_file(key_share_file, &raw, encryption)?
+    } else {
+        Zeroizing::new(raw)
+    };
+
+    let key_shares: HashMap<String, KeyShare<Secp256k1, SecurityLevel128>> = serde_json::from_slice(&key_share_json)
         .with_context(|| format!("Key shares deserialization failed for {}", key_share_file))?;
 
     info!("✓ Key shares loaded successfully. Account IDs: {:?}", key_shares.keys().collect::<Vec<_>>());
-    
+
     Ok(key_shares)
 }
 
+/// Decrypts an age-encrypted `key_share_file` whose raw bytes already start
+/// with [`AGE_MAGIC`], deriving the key from the passphrase named in
+/// `encryption`. The returned buffer zeroizes its contents on drop, same as
+/// the passphrase itself, so the plaintext share JSON doesn't linger in
+/// memory past deserialization any longer than necessary.
+fn decrypt_key_share_file(
+    key_share_file: &str,
+    raw: &[u8],
+    encryption: &KeyShareEncryptionMode,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let passphrase_env = match encryption {
+        KeyShareEncryptionMode::Encrypted { passphrase_env } => passphrase_env,
+        KeyShareEncryptionMode::Plaintext => {
+            return Err(anyhow!(
+                "{} is an age-encrypted envelope but mpc.key_share_encryption is 'plaintext'; \
+                 set it to 'encrypted' with a passphrase_env",
+                key_share_file
+            ));
+        }
+    };
+
+    let passphrase = Zeroizing::new(env::var(passphrase_env).with_context(|| {
+        format!(
+            "mpc.key_share_encryption.passphrase_env points at {}, which is not set",
+            passphrase_env
+        )
+    })?);
+    let identity = age::scrypt::Identity::new(passphrase.to_string());
+
+    let decryptor = age::Decryptor::new(raw)
+        .with_context(|| format!("Failed to read age header from {}", key_share_file))?;
+    let mut reader = decryptor
+        .decrypt(std::iter::once(&identity as &dyn age::Identity))
+        .with_context(|| format!("Failed to decrypt {} (wrong passphrase?)", key_share_file))?;
+
+    let mut decrypted = Zeroizing::new(Vec::new());
+    reader
+        .read_to_end(&mut decrypted)
+        .with_context(|| format!("Failed to read decrypted contents of {}", key_share_file))?;
+    Ok(decrypted)
+}
+
 /// Resolve key share file path: environment variable takes priority over config
 fn resolve_key_share_file(config_path: &str) -> String {
     match env::var(KEY_SHARE_FILE_ENV) {
@@ -44,27 +105,87 @@ fn resolve_key_share_file(config_path: &str) -> String {
     }
 }
 
+/// Runs distributed key generation with the other participants and writes
+/// the resulting share into `key_share_file`, so the subsequent
+/// `load_key_shares` call picks it up exactly like a trusted-dealer file.
+async fn run_dkg_provisioning(config: &SignServiceConfig, key_share_file: &str) -> Result<()> {
+    let account_id = config
+        .mpc
+        .dkg_account_id
+        .as_deref()
+        .context("mpc.dkg_account_id must be set when mpc.provisioning is 'dkg'")?;
+    let execution_id = config
+        .mpc
+        .dkg_execution_id
+        .as_deref()
+        .context("mpc.dkg_execution_id must be set when mpc.provisioning is 'dkg'")?;
+
+    let sse_url = reqwest::Url::parse(&config.server.sse.host)
+        .or_else(|_| reqwest::Url::parse(&format!("http://{}:{}", config.server.sse.host, config.server.sse.port)))
+        .context("failed to build SSE URL for DKG")?;
+    let client = Client::new(sse_url).context("failed to create DKG transport client")?;
+
+    info!("Provisioning key share for account_id {} via DKG", account_id);
+    // No operator-supplied member set/identity plumbed through sign-service
+    // config yet, so this DKG room stays unrestricted like every call site
+    // predating `participant::Client::with_identity` - see
+    // `ParticipantServer::set_room_members` for locking it down post-hoc.
+    participant::run_keygen(
+        &client,
+        account_id,
+        execution_id.as_bytes(),
+        config.server.participant.index,
+        config.mpc.total_participants,
+        key_share_file,
+        &[],
+    )
+    .await
+    .context("DKG key generation failed")?;
+
+    Ok(())
+}
+
 pub async fn run_services(config: SignServiceConfig) -> Result<()> {
     info!("Initializing Participant Server...");
 
     // Resolve key share file path (env var takes priority)
     let key_share_file = resolve_key_share_file(&config.mpc.key_share_file);
 
+    // Refuse to start a second instance bound to the same key share file;
+    // held until this function returns, which releases it on graceful
+    // shutdown.
+    let listen_addr = format!("{}:{}", config.server.host, config.server.port);
+    let _instance_lock = InstanceLock::acquire(config.data_dir.as_deref(), &key_share_file, &listen_addr)
+        .context("Failed to acquire single-instance lock")?;
+
+    if config.mpc.provisioning == KeyProvisioningMode::Dkg {
+        run_dkg_provisioning(&config, &key_share_file).await?;
+    }
+
     // Load key shares from the resolved file path
-    let key_shares = load_key_shares(&key_share_file)
+    let key_shares = load_key_shares(&key_share_file, &config.mpc.key_share_encryption)
         .context("Failed to load key shares")?;
     
     // Log the loaded key shares information
     let key_share_count = key_shares.len();
     let account_ids: Vec<String> = key_shares.keys().cloned().collect();
     
-    // Create Participant server
-    let participant_server = ParticipantServer::new(
+    // Create Participant server, securing the SSE/HTTP transport and this
+    // service's own gRPC endpoint with mutual TLS and/or a custom reconnect
+    // policy when configured.
+    let participant_server = ParticipantServer::new_with_transport(
         &config.gateway.url,
         &config.server.host,
         config.server.port,
         key_shares,
-    ).map_err(|e| anyhow::anyhow!("Failed to create participant server: {}", e))?;
+        RequesterPolicy::empty(),
+        config.tls.as_ref(),
+        config.reconnect,
+        Some(config.transport.mode),
+        config.proxy.as_ref(),
+    )
+    .map_err(|e| anyhow::anyhow!("Failed to create participant server: {}", e))?
+    .with_total_participants(config.mpc.total_participants);
     
     info!("Participant Server created - {}:{}", 
           config.server.host,
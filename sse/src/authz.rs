@@ -0,0 +1,278 @@
+use std::collections::HashSet;
+
+use alloy::primitives::{Address, keccak256};
+use alloy::signers::k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A room's registered member set and party threshold, supplied once via the
+/// admin `register` endpoint and consulted on every later `broadcast`/
+/// `issue_unique_idx` call. Modeled on an on-chain "key server set": it is
+/// configured once at room creation rather than re-validated or mutated per
+/// request, so a compromised or malicious sender can't grow the set after
+/// the fact.
+#[derive(Debug, Clone)]
+pub struct RoomPolicy {
+    members: HashSet<Address>,
+    party_count: usize,
+}
+
+impl RoomPolicy {
+    pub fn new(members: impl IntoIterator<Item = Address>, party_count: usize) -> Self {
+        Self {
+            members: members.into_iter().collect(),
+            party_count,
+        }
+    }
+
+    /// The registered party count; `issue_unique_idx` must not hand out more
+    /// indices than this.
+    pub fn party_count(&self) -> usize {
+        self.party_count
+    }
+
+    fn is_member(&self, address: &Address) -> bool {
+        self.members.contains(address)
+    }
+
+    /// Verifies that `signature` is a valid detached signature over
+    /// `(room_id, payload)` recovering to `claimed_sender`, and that
+    /// `claimed_sender` is a registered member of this room.
+    pub fn authorize_broadcast(
+        &self,
+        room_id: &str,
+        payload: &[u8],
+        claimed_sender: Address,
+        signature: &DetachedSignature,
+    ) -> Result<(), BroadcastAuthError> {
+        let digest = broadcast_digest(room_id, payload);
+        let recovered = recover_sender(&digest, signature).map_err(BroadcastAuthError::Malformed)?;
+
+        if recovered != claimed_sender {
+            return Err(BroadcastAuthError::SenderMismatch {
+                claimed: claimed_sender,
+                recovered,
+            });
+        }
+
+        if !self.is_member(&recovered) {
+            return Err(BroadcastAuthError::UnknownSender { sender: recovered });
+        }
+
+        Ok(())
+    }
+}
+
+/// A detached ECDSA signature (`r || s || v`) over a room broadcast, hex
+/// encoded for JSON transport, in the same `ecrecover`-compatible form used
+/// for requester authorization elsewhere in this workspace.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DetachedSignature {
+    pub r: String,
+    pub s: String,
+    pub v: u8,
+}
+
+/// A signed broadcast envelope: `payload` is the opaque message this room
+/// relays, `sender` is the address the caller claims signed it, and
+/// `signature` must verify over `(room_id, payload)` for that address.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SignedBroadcast {
+    pub sender: Address,
+    pub signature: DetachedSignature,
+    pub payload: String,
+}
+
+#[derive(Debug, Error)]
+pub enum BroadcastAuthError {
+    #[error("malformed broadcast signature: {0}")]
+    Malformed(anyhow::Error),
+    #[error("signature recovers to {recovered}, not the claimed sender {claimed}")]
+    SenderMismatch { claimed: Address, recovered: Address },
+    #[error("sender {sender} is not a registered member of this room")]
+    UnknownSender { sender: Address },
+}
+
+fn broadcast_digest(room_id: &str, payload: &[u8]) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(room_id.len() + payload.len());
+    buf.extend_from_slice(room_id.as_bytes());
+    buf.extend_from_slice(payload);
+    *keccak256(&buf)
+}
+
+/// Verifies that a room-registration request is signed by one of the
+/// `members` it registers, so reaching the relay isn't enough to pre-empt
+/// someone else's `room_id` with an attacker-chosen member set - the same
+/// "signature required before trust is granted" guarantee
+/// [`RoomPolicy::authorize_broadcast`] gives broadcasts, applied one step
+/// earlier, before a `RoomPolicy` even exists to check against.
+pub fn authorize_registration(
+    room_id: &str,
+    members: &[Address],
+    party_count: usize,
+    claimed_sender: Address,
+    signature: &DetachedSignature,
+) -> Result<(), BroadcastAuthError> {
+    let digest = registration_digest(room_id, members, party_count);
+    let recovered = recover_sender(&digest, signature).map_err(BroadcastAuthError::Malformed)?;
+
+    if recovered != claimed_sender {
+        return Err(BroadcastAuthError::SenderMismatch {
+            claimed: claimed_sender,
+            recovered,
+        });
+    }
+
+    if !members.contains(&recovered) {
+        return Err(BroadcastAuthError::UnknownSender { sender: recovered });
+    }
+
+    Ok(())
+}
+
+/// Digest a registration request signs over. `room_id` is length-prefixed
+/// since it's the only variable-length field ahead of others in the
+/// buffer - `party_count` and each `Address` are fixed-width, so nothing
+/// else needs a prefix to stay unambiguous.
+fn registration_digest(room_id: &str, members: &[Address], party_count: usize) -> [u8; 32] {
+    let mut buf = Vec::with_capacity(8 + room_id.len() + 8 + members.len() * 20);
+    buf.extend_from_slice(&(room_id.len() as u64).to_be_bytes());
+    buf.extend_from_slice(room_id.as_bytes());
+    buf.extend_from_slice(&(party_count as u64).to_be_bytes());
+    for member in members {
+        buf.extend_from_slice(member.as_slice());
+    }
+    *keccak256(&buf)
+}
+
+fn recover_sender(digest: &[u8], signature: &DetachedSignature) -> Result<Address> {
+    let r = alloy::hex::decode(&signature.r).context("malformed r component")?;
+    let s = alloy::hex::decode(&signature.s).context("malformed s component")?;
+    let sig = Signature::from_slice(&[r.as_slice(), s.as_slice()].concat())
+        .context("malformed signature")?;
+    let recovery_id = RecoveryId::try_from(signature.v)
+        .with_context(|| format!("invalid recovery id {}", signature.v))?;
+
+    let key = VerifyingKey::recover_from_prehash(digest, &sig, recovery_id)
+        .context("signature recovery failed")?;
+    address_of(&key)
+}
+
+fn address_of(key: &VerifyingKey) -> Result<Address> {
+    let uncompressed = key.to_encoded_point(false);
+    Ok(Address::from_slice(
+        &keccak256(&uncompressed.as_bytes()[1..])[12..],
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::signers::k256::ecdsa::{signature::hazmat::PrehashSigner, SigningKey};
+
+    fn sign(
+        signing_key: &SigningKey,
+        room_id: &str,
+        payload: &[u8],
+    ) -> (Address, DetachedSignature) {
+        sign_digest(signing_key, broadcast_digest(room_id, payload))
+    }
+
+    fn sign_registration(
+        signing_key: &SigningKey,
+        room_id: &str,
+        members: &[Address],
+        party_count: usize,
+    ) -> (Address, DetachedSignature) {
+        sign_digest(signing_key, registration_digest(room_id, members, party_count))
+    }
+
+    fn sign_digest(signing_key: &SigningKey, digest: [u8; 32]) -> (Address, DetachedSignature) {
+        let signature: Signature = signing_key
+            .sign_prehash(&digest)
+            .expect("signing a 32-byte digest should succeed");
+        let recovery_id = RecoveryId::trial_recovery_from_msg(signing_key.verifying_key(), &digest, &signature)
+            .expect("recovery id should be derivable from the known verifying key");
+
+        let bytes = signature.to_bytes();
+        let detached = DetachedSignature {
+            r: alloy::hex::encode(&bytes[..32]),
+            s: alloy::hex::encode(&bytes[32..]),
+            v: recovery_id.to_byte(),
+        };
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        (sender, detached)
+    }
+
+    #[test]
+    fn authorizes_registered_member() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        let policy = RoomPolicy::new([sender], 1);
+
+        let (claimed, signature) = sign(&signing_key, "room-1", b"hello");
+        assert!(policy
+            .authorize_broadcast("room-1", b"hello", claimed, &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn rejects_sender_outside_the_member_set() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        let other_member = Address::from_slice(&[0xAA; 20]);
+        let policy = RoomPolicy::new([other_member], 1);
+
+        let (claimed, signature) = sign(&signing_key, "room-1", b"hello");
+        let err = policy
+            .authorize_broadcast("room-1", b"hello", claimed, &signature)
+            .unwrap_err();
+        assert!(matches!(err, BroadcastAuthError::UnknownSender { sender: s } if s == sender));
+    }
+
+    #[test]
+    fn rejects_signature_not_bound_to_this_room() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        let policy = RoomPolicy::new([sender], 1);
+
+        let (claimed, signature) = sign(&signing_key, "room-1", b"hello");
+        let err = policy
+            .authorize_broadcast("room-2", b"hello", claimed, &signature)
+            .unwrap_err();
+        assert!(matches!(err, BroadcastAuthError::SenderMismatch { .. }));
+    }
+
+    #[test]
+    fn authorizes_registration_signed_by_a_member() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        let members = vec![sender, Address::from_slice(&[0xAA; 20])];
+
+        let (claimed, signature) = sign_registration(&signing_key, "room-1", &members, 2);
+        assert!(authorize_registration("room-1", &members, 2, claimed, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_registration_from_a_non_member() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        let members = vec![Address::from_slice(&[0xAA; 20]), Address::from_slice(&[0xBB; 20])];
+
+        let (claimed, signature) = sign_registration(&signing_key, "room-1", &members, 2);
+        let err = authorize_registration("room-1", &members, 2, claimed, &signature).unwrap_err();
+        assert!(matches!(err, BroadcastAuthError::UnknownSender { sender: s } if s == sender));
+    }
+
+    #[test]
+    fn rejects_registration_signature_not_bound_to_this_room_or_member_set() {
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        let sender = address_of(signing_key.verifying_key()).unwrap();
+        let members = vec![sender];
+
+        let (claimed, signature) = sign_registration(&signing_key, "room-1", &members, 1);
+        let err = authorize_registration("room-2", &members, 1, claimed, &signature).unwrap_err();
+        assert!(matches!(err, BroadcastAuthError::SenderMismatch { .. }));
+    }
+}
@@ -27,6 +27,27 @@ pub struct AppConfig {
 pub struct SSEConfig {
     pub host: String,
     pub port: u16,
+    /// Maximum number of past broadcast messages a `Room` keeps around for
+    /// replay via `Last-Event-ID`. Bounds the room's memory use instead of
+    /// retaining every message for the lifetime of a long-running session;
+    /// subscribers that fall further behind than this get an explicit
+    /// "expired, resubscribe" error instead of silently missing messages.
+    #[serde(default = "default_history_capacity")]
+    pub history_capacity: usize,
+    /// Origins allowed to make cross-origin requests to the relay. Empty
+    /// (the default) allows any origin, matching the behavior before this
+    /// was enforced. Can be refreshed after startup via
+    /// [`SseServer::set_cors_origins`] without dropping in-flight SSE
+    /// connections.
+    #[serde(default)]
+    pub cors_origins: Vec<String>,
+}
+
+/// Default `Room` history capacity when not overridden by config or env.
+pub const DEFAULT_ROOM_HISTORY_CAPACITY: usize = 4096;
+
+fn default_history_capacity() -> usize {
+    DEFAULT_ROOM_HISTORY_CAPACITY
 }
 
 impl AppConfig {
@@ -43,11 +64,26 @@ impl AppConfig {
                 error!("Invalid SSE_PORT configuration: {}", err);
                 err
             })?;
+        let history_capacity = env::var("SSE_HISTORY_CAPACITY")
+            .ok()
+            .map(|v| {
+                v.parse().map_err(|_| {
+                    let err = ConfigError::InvalidEnvVar(
+                        "Expected SSE_HISTORY_CAPACITY to be a number".to_string(),
+                    );
+                    error!("Invalid SSE_HISTORY_CAPACITY configuration: {}", err);
+                    err
+                })
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_ROOM_HISTORY_CAPACITY);
 
         let config = AppConfig {
             sse: SSEConfig {
                 host: sse_host,
                 port: sse_port,
+                history_capacity,
+                cors_origins: Vec::new(),
             },
         };
 
@@ -1,23 +1,33 @@
+pub mod authz;
 pub mod config;
+mod sessions;
 
+use std::collections::VecDeque;
 use std::collections::hash_map::{Entry, HashMap};
 use std::sync::{
-    Arc,
-    atomic::{AtomicU16, Ordering},
+    Arc, RwLock as StdRwLock,
+    atomic::{AtomicU16, AtomicU64, Ordering},
 };
 
+use actix_cors::Cors;
 use actix_web::Responder;
 use actix_web::{
     App, HttpRequest, HttpResponse, HttpServer, Result as ActixResult, middleware::Logger, web,
 };
 use actix_web::dev::ServerHandle;
 use actix_web_lab::sse::{self, Sse};
-use futures_util::Stream;
-use log::{debug, info};
+use alloy::primitives::Address;
+use futures_util::{Stream, StreamExt, TryStreamExt};
+use log::{debug, error, info};
 use serde::{Deserialize, Serialize};
-use tokio::sync::{Notify, RwLock, Mutex};
+use thiserror::Error;
+use tokio::sync::{Notify, RwLock, Mutex, mpsc};
+use tokio_util::codec::{FramedRead, LengthDelimitedCodec};
+use tokio_util::io::StreamReader;
 
-pub use config::{AppConfig, SSEConfig};
+pub use authz::{BroadcastAuthError, DetachedSignature, RoomPolicy, SignedBroadcast};
+pub use config::{AppConfig, SSEConfig, DEFAULT_ROOM_HISTORY_CAPACITY};
+use sessions::{SessionGuard, SessionRegistry};
 
 /// Main structure of SSE Server, encapsulating all core functions
 #[derive(Clone)]
@@ -25,18 +35,65 @@ pub struct SseServer {
     db: web::Data<Db>,
     config: AppConfig,
     server_handle: Arc<Mutex<Option<ServerHandle>>>,
+    /// rustls server identity to listen with instead of plain HTTP, if
+    /// mutual TLS is configured for the relay. Built by the caller (e.g.
+    /// from a `participant::TlsConfig`) so this crate doesn't need to know
+    /// about any particular config file format.
+    tls: Option<rustls::ServerConfig>,
+    /// Origins allowed through CORS, checked fresh on every request so
+    /// [`SseServer::set_cors_origins`] can retune it live (e.g. from a
+    /// `SIGHUP` config reload) without restarting the listener or dropping
+    /// in-flight SSE connections. A plain `std::sync::RwLock` is enough
+    /// since the read side is synchronous, uncontended, and brief.
+    cors_origins: Arc<StdRwLock<Vec<String>>>,
+    /// Rooms with an active subscriber, so a graceful shutdown can drain
+    /// in-flight sessions instead of tearing them down mid-round; see
+    /// [`Self::stop_accepting_sessions`] and [`Self::active_session_count`].
+    sessions: SessionRegistry,
 }
 
 impl SseServer {
     /// Create a new SSE Server instance
     pub fn new(config: AppConfig) -> Self {
+        let cors_origins = Arc::new(StdRwLock::new(config.sse.cors_origins.clone()));
         Self {
-            db: web::Data::new(Db::empty()),
+            db: web::Data::new(Db::with_history_capacity(config.sse.history_capacity)),
             config,
             server_handle: Arc::new(Mutex::new(None)),
+            tls: None,
+            cors_origins,
+            sessions: SessionRegistry::new(),
         }
     }
 
+    /// Serve behind mutual TLS using `tls_config` instead of plain HTTP.
+    pub fn with_tls(mut self, tls_config: rustls::ServerConfig) -> Self {
+        self.tls = Some(tls_config);
+        self
+    }
+
+    /// Replaces the allowed CORS origins used by requests from now on;
+    /// connections already streaming are unaffected. Lets operators retune
+    /// allowed origins on a running relay, e.g. from a `SIGHUP`-triggered
+    /// config reload.
+    pub fn set_cors_origins(&self, origins: Vec<String>) {
+        *self.cors_origins.write().expect("cors origins lock poisoned") = origins;
+    }
+
+    /// Stops admitting new room subscriptions, the first phase of a graceful
+    /// shutdown drain; subscriptions already open are unaffected until they
+    /// finish or [`Self::shutdown`] is called. Pairs with
+    /// [`Self::active_session_count`] so the caller can wait out a grace
+    /// period before force-closing the listener.
+    pub fn stop_accepting_sessions(&self) {
+        self.sessions.stop_accepting();
+    }
+
+    /// Number of rooms with at least one subscriber still connected.
+    pub fn active_session_count(&self) -> usize {
+        self.sessions.active_count()
+    }
+
     /// Create SSE Server with default config
     pub fn with_default_config() -> Result<Self, config::ConfigError> {
         let config = AppConfig::from_env()?;
@@ -51,13 +108,27 @@ impl SseServer {
 
         let db = self.db.clone();
         let server_handle = self.server_handle.clone();
+        let cors_origins = self.cors_origins.clone();
+        let sessions = web::Data::new(self.sessions.clone());
+
+        let http_server = HttpServer::new(move || {
+            let cors_origins = cors_origins.clone();
+            let cors = Cors::default()
+                .allowed_origin_fn(move |origin, _req_head| {
+                    let origins = cors_origins.read().expect("cors origins lock poisoned");
+                    origins.is_empty()
+                        || origins.iter().any(|allowed| allowed.as_bytes() == origin.as_bytes())
+                })
+                .allow_any_method()
+                .allow_any_header();
 
-        let server = HttpServer::new(move || {
             App::new()
                 .app_data(db.clone())
+                .app_data(sessions.clone())
                 .app_data(
                     web::PayloadConfig::new(100 * 1024 * 1024), // 100MB limit
                 )
+                .wrap(cors)
                 .wrap(Logger::default())
                 .route("/rooms/{room_id}/subscribe", web::get().to(subscribe))
                 .route(
@@ -65,9 +136,20 @@ impl SseServer {
                     web::post().to(issue_idx),
                 )
                 .route("/rooms/{room_id}/broadcast", web::post().to(broadcast))
-        })
-        .bind(address)?
-        .run();
+                .route(
+                    "/rooms/{room_id}/broadcast_stream",
+                    web::post().to(broadcast_stream),
+                )
+                .route("/rooms/{room_id}/register", web::post().to(register_room))
+        });
+
+        let server = match &self.tls {
+            Some(tls_config) => {
+                info!("SSE server TLS enabled");
+                http_server.bind_rustls_0_23(address, tls_config.clone())?.run()
+            }
+            None => http_server.bind(address)?.run(),
+        };
 
         // Store the server handle for graceful shutdown
         {
@@ -97,6 +179,41 @@ impl SseServer {
         }
     }
 
+    /// Stops admitting new room subscriptions and waits up to
+    /// `grace_period` for in-flight ones to finish on their own before
+    /// tearing the listener down, so a signing round isn't cut off
+    /// mid-way by a fixed actix shutdown timeout. Logs how many sessions
+    /// drained within the grace period vs. were still open when it
+    /// force-closed.
+    pub async fn shutdown_after_drain(&self, grace_period: std::time::Duration) -> anyhow::Result<()> {
+        self.stop_accepting_sessions();
+
+        let initial = self.active_session_count();
+        if initial > 0 {
+            info!(
+                "Draining {} active SSE session(s), grace period {:?}",
+                initial, grace_period
+            );
+            let deadline = tokio::time::Instant::now() + grace_period;
+            while self.active_session_count() > 0 && tokio::time::Instant::now() < deadline {
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            }
+        }
+
+        let remaining = self.active_session_count();
+        let drained = initial.saturating_sub(remaining);
+        if remaining > 0 {
+            log::warn!(
+                "Shutdown grace period elapsed with {} session(s) still active; force-closing",
+                remaining
+            );
+        } else if initial > 0 {
+            info!("All {} active session(s) drained gracefully", drained);
+        }
+
+        self.shutdown().await
+    }
+
     /// Get database instance (for custom handling)
     pub fn db(&self) -> &web::Data<Db> {
         &self.db
@@ -111,15 +228,30 @@ impl SseServer {
 /// Database structure, manages all rooms
 pub struct Db {
     rooms: RwLock<HashMap<String, Arc<Room>>>,
+    history_capacity: usize,
 }
 
 impl Db {
+    /// Creates a `Db` whose rooms keep [`DEFAULT_ROOM_HISTORY_CAPACITY`]
+    /// messages of history each.
     pub fn empty() -> Self {
+        Self::with_history_capacity(DEFAULT_ROOM_HISTORY_CAPACITY)
+    }
+
+    pub fn with_history_capacity(history_capacity: usize) -> Self {
         Self {
             rooms: RwLock::new(HashMap::new()),
+            history_capacity,
         }
     }
 
+    /// Returns the room for `room_id`, creating it as an unrestricted room
+    /// (no registered member set or party count) if it doesn't exist yet.
+    ///
+    /// This is the legacy/demo path kept for rooms nobody has registered
+    /// via [`register_room`](Self::register_room): same as an empty
+    /// `RequesterPolicy` elsewhere in this workspace, an unrestricted room
+    /// accepts any sender without verifying them.
     pub async fn get_room_or_create_for_index(&self, room_id: &str) -> Arc<Room> {
         let rooms = self.rooms.read().await;
         if let Some(room) = rooms.get(room_id) {
@@ -135,37 +267,116 @@ impl Db {
                 entry.get().clone()
             }
             Entry::Vacant(entry) => {
-                info!("Creating new room '{}'", room_id);
-                entry.insert(Arc::new(Room::empty())).clone()
+                info!("Creating new unrestricted room '{}'", room_id);
+                entry
+                    .insert(Arc::new(Room::with_capacity(self.history_capacity)))
+                    .clone()
+            }
+        }
+    }
+
+    /// Registers `room_id` with its allowed member set and party count
+    /// before anyone has subscribed, broadcast, or issued an index in it.
+    /// The policy is read once here and never changes afterwards; if the
+    /// room already exists (registered or not) this fails rather than
+    /// silently replacing its policy.
+    pub async fn register_room(
+        &self,
+        room_id: &str,
+        members: Vec<Address>,
+        party_count: usize,
+    ) -> Result<Arc<Room>, RoomAlreadyExists> {
+        let mut rooms = self.rooms.write().await;
+        match rooms.entry(room_id.to_owned()) {
+            Entry::Occupied(_) => Err(RoomAlreadyExists {
+                room_id: room_id.to_owned(),
+            }),
+            Entry::Vacant(entry) => {
+                info!(
+                    "Registering room '{}' with {} member(s), party_count {}",
+                    room_id,
+                    members.len(),
+                    party_count
+                );
+                let policy = RoomPolicy::new(members, party_count);
+                let room = Arc::new(Room::with_policy(
+                    room_id.to_owned(),
+                    policy,
+                    self.history_capacity,
+                ));
+                Ok(entry.insert(room).clone())
             }
         }
     }
 }
 
-/// Room structure, manages messages and subscribers
-pub struct Room {
-    messages: RwLock<Vec<String>>,
+/// `room_id` already has a room (registered or unrestricted); a room's
+/// policy is read once at creation and can't be registered a second time.
+#[derive(Debug, Error)]
+#[error("room '{room_id}' already exists")]
+pub struct RoomAlreadyExists {
+    room_id: String,
+}
+
+/// `issue_unique_idx` can't hand out another index.
+#[derive(Debug, Error)]
+pub enum IssueIndexError {
+    /// The counter has already issued `issued` indices, which is more than
+    /// fit in the `u16` party index the MPC protocol uses; handing out any
+    /// more would require wrapping back into indices already in use.
+    #[error("room has issued {issued} party indices, exhausting the u16 index space")]
+    IndexSpaceExhausted { issued: u64 },
+    /// The room's registered policy only expects `party_count` parties, and
+    /// that many indices have already been issued.
+    #[error(
+        "room has already issued its registered party_count of {party_count} indices \
+         (attempted to issue index {issued})"
+    )]
+    PartyCountExhausted { issued: u64, party_count: usize },
+}
+
+/// A subscriber asked to resume from `requested`, but that event has already
+/// fallen out of the room's bounded history (the oldest event still kept is
+/// `oldest`). The caller missed messages and must restart its protocol
+/// round rather than silently resume from the wrong point.
+#[derive(Debug, Error)]
+#[error(
+    "requested event {requested} has expired from this room's {capacity}-message history \
+     (oldest available event is {oldest}); resubscribe from the latest offset"
+)]
+pub struct SubscriptionExpired {
+    requested: u64,
+    oldest: u64,
+    capacity: usize,
+}
+
+/// The state shared between a `Room` and the background task that owns
+/// writing into its message history, so `publish` can hand a message off to
+/// that task instead of taking the write lock itself.
+struct RoomState {
+    /// Bounded ring buffer of the most recent `capacity` broadcast messages.
+    messages: RwLock<VecDeque<String>>,
+    capacity: usize,
+    /// Absolute event id of `messages[0]`; advances every time a message
+    /// ages out of the ring buffer, so `Subscription` can tell an expired
+    /// offset apart from one that just hasn't arrived yet.
+    base_offset: AtomicU64,
     message_appeared: Notify,
     subscribers: AtomicU16,
-    next_idx: AtomicU16,
+    next_idx: AtomicU64,
 }
 
-impl Room {
-    pub fn empty() -> Self {
-        Self {
-            messages: RwLock::new(vec![]),
-            message_appeared: Notify::new(),
-            subscribers: AtomicU16::new(0),
-            next_idx: AtomicU16::new(0),
+impl RoomState {
+    async fn append(&self, message: String) {
+        let mut messages = self.messages.write().await;
+        if messages.len() >= self.capacity {
+            messages.pop_front();
+            self.base_offset.fetch_add(1, Ordering::SeqCst);
         }
-    }
+        messages.push_back(message);
 
-    pub async fn publish(self: &Arc<Self>, message: String) {
-        let mut messages = self.messages.write().await;
-        let message_id = messages.len();
-        messages.push(message);
+        let message_id = self.base_offset.load(Ordering::SeqCst) + messages.len() as u64 - 1;
         let subscriber_count = self.subscribers.load(Ordering::SeqCst);
-
         debug!(
             "Published message {} to {} subscribers",
             message_id, subscriber_count
@@ -173,10 +384,87 @@ impl Room {
 
         self.message_appeared.notify_waiters();
     }
+}
+
+/// Room structure, manages messages and subscribers.
+///
+/// Broadcast payloads are not written into the history directly by
+/// `publish`: they're handed off over an unbounded channel to a dedicated
+/// task that owns the write lock, so a slow subscriber holding the read
+/// lock can never make a publisher's request wait on it.
+pub struct Room {
+    /// Empty for unrestricted rooms (see [`Room::with_capacity`]); set to
+    /// the real room id for registered rooms, so broadcast signatures bind
+    /// to the room they were sent to.
+    id: String,
+    policy: Option<RoomPolicy>,
+    state: Arc<RoomState>,
+    outbox: mpsc::UnboundedSender<String>,
+}
+
+impl Room {
+    /// Creates an unrestricted room keeping
+    /// [`DEFAULT_ROOM_HISTORY_CAPACITY`] messages of history.
+    pub fn empty() -> Self {
+        Self::with_capacity(DEFAULT_ROOM_HISTORY_CAPACITY)
+    }
+
+    /// Creates an unrestricted room: no registered member set or party
+    /// count, so `broadcast` accepts unsigned messages and
+    /// `issue_unique_idx` is only bounded by the `u16` index space.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(String::new(), None, capacity)
+    }
+
+    /// Creates a room with a registered member set and party count; see
+    /// [`Db::register_room`].
+    pub fn with_policy(id: String, policy: RoomPolicy, capacity: usize) -> Self {
+        Self::new(id, Some(policy), capacity)
+    }
+
+    fn new(id: String, policy: Option<RoomPolicy>, capacity: usize) -> Self {
+        let state = Arc::new(RoomState {
+            messages: RwLock::new(VecDeque::with_capacity(capacity.min(1024))),
+            capacity,
+            base_offset: AtomicU64::new(0),
+            message_appeared: Notify::new(),
+            subscribers: AtomicU16::new(0),
+            next_idx: AtomicU64::new(0),
+        });
+
+        let (outbox, mut inbox) = mpsc::unbounded_channel();
+        let writer_state = state.clone();
+        tokio::spawn(async move {
+            while let Some(message) = inbox.recv().await {
+                writer_state.append(message).await;
+            }
+        });
+
+        Self {
+            id,
+            policy,
+            state,
+            outbox,
+        }
+    }
 
-    pub fn subscribe(self: Arc<Self>, last_seen_msg: Option<u16>) -> Subscription {
-        let new_count = self.subscribers.fetch_add(1, Ordering::SeqCst) + 1;
-        let next_event = last_seen_msg.map(|i| i + 1).unwrap_or(0);
+    /// The room's registered policy, or `None` if it's unrestricted.
+    pub fn policy(&self) -> Option<&RoomPolicy> {
+        self.policy.as_ref()
+    }
+
+    pub async fn publish(&self, message: String) {
+        if self.outbox.send(message).is_err() {
+            // The writer task only stops once every sender (including this
+            // one) is dropped, so this can't actually happen; kept as a
+            // safe fallback instead of unwrapping.
+            error!("Room's writer task is gone, dropping published message");
+        }
+    }
+
+    pub fn subscribe(self: &Arc<Self>, last_seen_event: Option<u64>) -> Subscription {
+        let new_count = self.state.subscribers.fetch_add(1, Ordering::SeqCst) + 1;
+        let next_event = last_seen_event.map(|i| i + 1).unwrap_or(0);
 
         debug!(
             "New subscription created, subscribers: {}, starting from event: {}",
@@ -184,37 +472,60 @@ impl Room {
         );
 
         Subscription {
-            room: self,
+            room: self.clone(),
             next_event,
         }
     }
 
-    pub fn issue_unique_idx(&self) -> u16 {
-        self.next_idx.fetch_add(1, Ordering::Relaxed)
+    pub fn issue_unique_idx(&self) -> Result<u16, IssueIndexError> {
+        let issued = self.state.next_idx.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(policy) = &self.policy {
+            if issued >= policy.party_count() as u64 {
+                return Err(IssueIndexError::PartyCountExhausted {
+                    issued,
+                    party_count: policy.party_count(),
+                });
+            }
+        }
+
+        u16::try_from(issued).map_err(|_| IssueIndexError::IndexSpaceExhausted { issued })
     }
 }
 
 /// Subscription structure
 pub struct Subscription {
     room: Arc<Room>,
-    next_event: u16,
+    next_event: u64,
 }
 
 impl Subscription {
-    pub async fn next(&mut self) -> (u16, String) {
+    pub async fn next(&mut self) -> Result<(u64, String), SubscriptionExpired> {
         loop {
-            let history = self.room.messages.read().await;
-            if let Some(msg) = history.get(usize::from(self.next_event)) {
+            let history = self.room.state.messages.read().await;
+            let base = self.room.state.base_offset.load(Ordering::SeqCst);
+
+            if self.next_event < base {
+                return Err(SubscriptionExpired {
+                    requested: self.next_event,
+                    oldest: base,
+                    capacity: self.room.state.capacity,
+                });
+            }
+
+            let slot = usize::try_from(self.next_event - base).unwrap_or(usize::MAX);
+            if let Some(msg) = history.get(slot) {
                 let event_id = self.next_event;
                 self.next_event = event_id + 1;
                 debug!("Delivering event {} to subscriber", event_id);
-                return (event_id, msg.clone());
+                return Ok((event_id, msg.clone()));
             }
+
             debug!(
                 "No new messages, waiting for notification (current event: {})",
                 self.next_event
             );
-            let notification = self.room.message_appeared.notified();
+            let notification = self.room.state.message_appeared.notified();
             drop(history);
             notification.await;
         }
@@ -223,7 +534,7 @@ impl Subscription {
 
 impl Drop for Subscription {
     fn drop(&mut self) {
-        let remaining = self.room.subscribers.fetch_sub(1, Ordering::SeqCst) - 1;
+        let remaining = self.room.state.subscribers.fetch_sub(1, Ordering::SeqCst) - 1;
         debug!("Subscription dropped, remaining subscribers: {}", remaining);
 
         if remaining == 0 {
@@ -240,10 +551,22 @@ pub struct IssuedUniqueIdx {
 // HTTP handler functions
 async fn subscribe(
     db: web::Data<Db>,
+    sessions: web::Data<SessionRegistry>,
     path: web::Path<String>,
     req: HttpRequest,
 ) -> ActixResult<HttpResponse> {
     let room_id = path.into_inner();
+
+    if !sessions.is_accepting() {
+        info!(
+            "Rejecting new subscription to room '{}': relay is draining for shutdown",
+            room_id
+        );
+        return Err(actix_web::error::ErrorServiceUnavailable(
+            "relay is draining for shutdown, not accepting new sessions",
+        ));
+    }
+
     let last_seen_msg = extract_last_event_id(&req);
 
     info!(
@@ -252,7 +575,7 @@ async fn subscribe(
     );
 
     let room = db.get_room_or_create_for_index(&room_id).await;
-    let subscribers = room.subscribers.load(Ordering::SeqCst);
+    let subscribers = room.state.subscribers.load(Ordering::SeqCst);
     let subscription = room.subscribe(last_seen_msg);
 
     debug!(
@@ -260,7 +583,7 @@ async fn subscribe(
         room_id, subscribers
     );
 
-    let stream = subscription_to_stream(subscription);
+    let stream = subscription_to_stream(subscription, sessions.get_ref().clone(), room_id);
 
     Ok(Sse::from_stream(stream)
         .with_retry_duration(std::time::Duration::from_secs(5))
@@ -273,21 +596,60 @@ async fn issue_idx(
 ) -> ActixResult<web::Json<IssuedUniqueIdx>> {
     let room_id = path.into_inner();
     let room = db.get_room_or_create_for_index(&room_id).await;
-    let idx = room.issue_unique_idx();
+    let idx = room.issue_unique_idx().map_err(|e| {
+        error!("Failed to issue index for room '{}': {}", room_id, e);
+        match e {
+            IssueIndexError::IndexSpaceExhausted { .. } => {
+                actix_web::error::ErrorServiceUnavailable(e.to_string())
+            }
+            IssueIndexError::PartyCountExhausted { .. } => {
+                actix_web::error::ErrorForbidden(e.to_string())
+            }
+        }
+    })?;
 
     info!("Issued unique index {} for room '{}'", idx, room_id);
 
     Ok(web::Json(IssuedUniqueIdx { unique_idx: idx }))
 }
 
+/// Accepts either a bare string body (legacy, only valid for unrestricted
+/// rooms) or a [`SignedBroadcast`] JSON envelope (required once a room has
+/// been registered via `register_room`), matching the "authorization not
+/// configured" fallback `RequesterPolicy` uses elsewhere in this workspace.
 async fn broadcast(
     db: web::Data<Db>,
     path: web::Path<String>,
-    message: String,
+    body: web::Bytes,
 ) -> ActixResult<HttpResponse> {
     let room_id = path.into_inner();
     let room = db.get_room_or_create_for_index(&room_id).await;
 
+    let message = match room.policy() {
+        None => String::from_utf8(body.to_vec())
+            .map_err(|e| actix_web::error::ErrorBadRequest(format!("invalid utf-8 payload: {e}")))?,
+        Some(policy) => {
+            let envelope: SignedBroadcast = serde_json::from_slice(&body).map_err(|e| {
+                error!("Rejected malformed signed broadcast for room '{}': {}", room_id, e);
+                actix_web::error::ErrorBadRequest(format!("malformed signed broadcast: {e}"))
+            })?;
+
+            policy
+                .authorize_broadcast(
+                    &room_id,
+                    envelope.payload.as_bytes(),
+                    envelope.sender,
+                    &envelope.signature,
+                )
+                .map_err(|e| {
+                    error!("Rejected broadcast to room '{}': {}", room_id, e);
+                    actix_web::error::ErrorUnauthorized(e.to_string())
+                })?;
+
+            envelope.payload
+        }
+    };
+
     debug!(
         "Broadcasting message to room '{}', message length: {} bytes",
         room_id,
@@ -301,28 +663,152 @@ async fn broadcast(
     Ok(HttpResponse::Ok().finish())
 }
 
-fn extract_last_event_id(req: &HttpRequest) -> Option<u16> {
+/// Counterpart to `broadcast` for `participant::TransportMode::Stream`
+/// clients: accepts a single long-lived chunked upload instead of one POST
+/// per message, splitting it back into individual messages with a 4-byte
+/// length-delimited codec (the framing `Room::open_broadcast_stream` in
+/// `participant::client` writes) and publishing each as it arrives. Applies
+/// the same per-message authorization as `broadcast` so a registered room
+/// can't be broadcast to unsigned just by switching transport.
+async fn broadcast_stream(
+    db: web::Data<Db>,
+    path: web::Path<String>,
+    payload: web::Payload,
+) -> ActixResult<HttpResponse> {
+    let room_id = path.into_inner();
+    let room = db.get_room_or_create_for_index(&room_id).await;
+
+    let byte_stream = payload.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e));
+    let mut frames = FramedRead::new(StreamReader::new(byte_stream), LengthDelimitedCodec::new());
+
+    let mut accepted = 0usize;
+    while let Some(frame) = frames.next().await {
+        let frame = frame.map_err(|e| {
+            error!("Stream broadcast to room '{}' failed to decode a frame: {}", room_id, e);
+            actix_web::error::ErrorBadRequest(format!("malformed frame: {e}"))
+        })?;
+
+        let message = match room.policy() {
+            None => String::from_utf8(frame.to_vec())
+                .map_err(|e| actix_web::error::ErrorBadRequest(format!("invalid utf-8 payload: {e}")))?,
+            Some(policy) => {
+                let envelope: SignedBroadcast = serde_json::from_slice(&frame).map_err(|e| {
+                    error!("Rejected malformed streamed broadcast frame for room '{}': {}", room_id, e);
+                    actix_web::error::ErrorBadRequest(format!("malformed signed broadcast: {e}"))
+                })?;
+
+                policy
+                    .authorize_broadcast(
+                        &room_id,
+                        envelope.payload.as_bytes(),
+                        envelope.sender,
+                        &envelope.signature,
+                    )
+                    .map_err(|e| {
+                        error!("Rejected streamed broadcast to room '{}': {}", room_id, e);
+                        actix_web::error::ErrorUnauthorized(e.to_string())
+                    })?;
+
+                envelope.payload
+            }
+        };
+
+        room.publish(message).await;
+        accepted += 1;
+    }
+
+    debug!(
+        "Stream broadcast to room '{}' completed, {} frame(s) accepted",
+        room_id, accepted
+    );
+
+    Ok(HttpResponse::Ok().finish())
+}
+
+/// Admin endpoint registering `room_id` with its allowed member set and
+/// party count before anyone uses it. See [`Db::register_room`].
+///
+/// Requires a detached signature over the registration request from one of
+/// the `members` being registered, the same "authorization not configured"
+/// trust model `broadcast` uses for its own envelope - otherwise anyone who
+/// can reach the relay could race a legitimate session to pre-register its
+/// `room_id` with a member set of their own choosing.
+async fn register_room(
+    db: web::Data<Db>,
+    path: web::Path<String>,
+    request: web::Json<RegisterRoomRequest>,
+) -> ActixResult<HttpResponse> {
+    let room_id = path.into_inner();
+    let request = request.into_inner();
+
+    authz::authorize_registration(
+        &room_id,
+        &request.members,
+        request.party_count,
+        request.sender,
+        &request.signature,
+    )
+    .map_err(|e| {
+        error!("Rejected registration for room '{}': {}", room_id, e);
+        actix_web::error::ErrorUnauthorized(e.to_string())
+    })?;
+
+    db.register_room(&room_id, request.members, request.party_count)
+        .await
+        .map_err(|e| {
+            error!("Failed to register room '{}': {}", room_id, e);
+            actix_web::error::ErrorConflict(e.to_string())
+        })?;
+
+    Ok(HttpResponse::Created().finish())
+}
+
+#[derive(Debug, Deserialize)]
+struct RegisterRoomRequest {
+    members: Vec<Address>,
+    party_count: usize,
+    /// Address claiming to have signed this request; must be one of
+    /// `members`. See [`authz::authorize_registration`].
+    sender: Address,
+    signature: DetachedSignature,
+}
+
+fn extract_last_event_id(req: &HttpRequest) -> Option<u64> {
     req.headers()
         .get("Last-Event-ID")
         .and_then(|header| header.to_str().ok())
-        .and_then(|id_str| id_str.parse::<u16>().ok())
+        .and_then(|id_str| id_str.parse::<u64>().ok())
 }
 
 fn subscription_to_stream(
     mut subscription: Subscription,
+    sessions: SessionRegistry,
+    room_id: String,
 ) -> impl Stream<Item = Result<sse::Event, actix_web::Error>> {
     async_stream::stream! {
+        // Held for the lifetime of the stream so the session registry
+        // reflects this subscription even if the client disconnects
+        // without the loop below ever returning normally.
+        let _session = SessionGuard::new(sessions, room_id);
         loop {
             // Check if the client has disconnected by yielding a test event
             // If the client is gone, this will cause the stream to be dropped
-            let (id, msg) = subscription.next().await;
-            {
-                let event = sse::Event::Data(
-                    sse::Data::new(msg)
-                        .event("new-message")
-                        .id(id.to_string())
-                );
-                yield Ok(event);
+            match subscription.next().await {
+                Ok((id, msg)) => {
+                    let event = sse::Event::Data(
+                        sse::Data::new(msg)
+                            .event("new-message")
+                            .id(id.to_string())
+                    );
+                    yield Ok(event);
+                }
+                Err(expired) => {
+                    debug!("Ending subscription stream: {}", expired);
+                    yield Ok(sse::Event::Data(
+                        sse::Data::new(expired.to_string()).event("expired"),
+                    ));
+                    return;
+                }
             }
         }
     }
@@ -332,12 +818,35 @@ fn subscription_to_stream(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_room_creation() {
+    #[tokio::test]
+    async fn test_room_creation() {
         let room = Room::empty();
-        assert_eq!(room.subscribers.load(Ordering::SeqCst), 0);
-        assert_eq!(room.issue_unique_idx(), 0);
-        assert_eq!(room.issue_unique_idx(), 1);
+        assert_eq!(room.state.subscribers.load(Ordering::SeqCst), 0);
+        assert_eq!(room.issue_unique_idx().unwrap(), 0);
+        assert_eq!(room.issue_unique_idx().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_room_history_expiry() {
+        let room = Room::with_capacity(2);
+        let room = Arc::new(room);
+
+        room.publish("one".to_string()).await;
+        room.publish("two".to_string()).await;
+        room.publish("three".to_string()).await;
+
+        // Give the writer task a chance to drain the channel.
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let mut subscription = room.clone().subscribe(None);
+        let err = subscription.next().await.unwrap_err();
+        assert_eq!(err.requested, 0);
+        assert_eq!(err.oldest, 1);
+
+        let mut subscription = room.clone().subscribe(Some(0));
+        let (id, msg) = subscription.next().await.unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(msg, "two");
     }
 
     #[tokio::test]
@@ -345,17 +854,45 @@ mod tests {
         let db = Db::empty();
         let room1 = db.get_room_or_create_for_index("test_room").await;
         let room2 = db.get_room_or_create_for_index("test_room").await;
-        
+
         // Should return the same room instance
         assert!(Arc::ptr_eq(&room1, &room2));
     }
 
+    #[tokio::test]
+    async fn test_db_register_room_rejects_duplicate() {
+        let db = Db::empty();
+        db.register_room("test_room", vec![], 2).await.unwrap();
+
+        let err = db.register_room("test_room", vec![], 2).await.unwrap_err();
+        assert_eq!(err.room_id, "test_room");
+    }
+
+    #[test]
+    fn test_issue_unique_idx_enforces_registered_party_count() {
+        let room = Room::with_policy("test_room".to_string(), RoomPolicy::new([], 2), 16);
+
+        assert_eq!(room.issue_unique_idx().unwrap(), 0);
+        assert_eq!(room.issue_unique_idx().unwrap(), 1);
+
+        let err = room.issue_unique_idx().unwrap_err();
+        assert!(matches!(
+            err,
+            IssueIndexError::PartyCountExhausted {
+                issued: 2,
+                party_count: 2
+            }
+        ));
+    }
+
     #[test]
     fn test_sse_server_creation() {
         let config = AppConfig {
             sse: SSEConfig {
                 host: "127.0.0.1".to_string(),
                 port: 8080,
+                history_capacity: DEFAULT_ROOM_HISTORY_CAPACITY,
+                cors_origins: Vec::new(),
             },
         };
 
@@ -370,6 +907,8 @@ mod tests {
             sse: SSEConfig {
                 host: "127.0.0.1".to_string(),
                 port: 8080,
+                history_capacity: DEFAULT_ROOM_HISTORY_CAPACITY,
+                cors_origins: Vec::new(),
             },
         };
 
@@ -386,6 +925,8 @@ mod tests {
             sse: SSEConfig {
                 host: "127.0.0.1".to_string(),
                 port: 8080,
+                history_capacity: DEFAULT_ROOM_HISTORY_CAPACITY,
+                cors_origins: Vec::new(),
             },
         };
 
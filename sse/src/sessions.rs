@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// How many subscribers a room currently has registered in a
+/// [`SessionRegistry`]. A room is considered an active session as long as
+/// this is above zero; tracked independently of `Room`'s own subscriber
+/// count so the registry doesn't need a reference into `Room` internals.
+#[derive(Debug, Clone, Copy, Default)]
+struct SessionState {
+    subscriber_count: u32,
+}
+
+/// Tracks which rooms currently have a subscriber connected, so a graceful
+/// shutdown can stop admitting new subscriptions and wait for the existing
+/// ones to finish instead of tearing a room down mid-round. Register and
+/// deregister around a subscription's lifetime, ideally via the [`SessionGuard`]
+/// RAII wrapper so a dropped future can't leak an entry.
+#[derive(Clone)]
+pub struct SessionRegistry {
+    sessions: Arc<Mutex<HashMap<String, SessionState>>>,
+    accepting: Arc<AtomicBool>,
+}
+
+impl SessionRegistry {
+    pub fn new() -> Self {
+        Self {
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            accepting: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    /// Whether new sessions should be admitted; `false` once
+    /// [`Self::stop_accepting`] has been called to begin a graceful shutdown
+    /// drain.
+    pub fn is_accepting(&self) -> bool {
+        self.accepting.load(Ordering::SeqCst)
+    }
+
+    /// Stops admitting new sessions. Sessions already registered are
+    /// unaffected and must still be deregistered normally as they finish.
+    pub fn stop_accepting(&self) {
+        self.accepting.store(false, Ordering::SeqCst);
+    }
+
+    pub fn register(&self, room_id: &str) {
+        let mut sessions = self.sessions.lock().expect("session registry lock poisoned");
+        sessions.entry(room_id.to_string()).or_default().subscriber_count += 1;
+    }
+
+    pub fn deregister(&self, room_id: &str) {
+        let mut sessions = self.sessions.lock().expect("session registry lock poisoned");
+        if let Some(state) = sessions.get_mut(room_id) {
+            state.subscriber_count = state.subscriber_count.saturating_sub(1);
+            if state.subscriber_count == 0 {
+                sessions.remove(room_id);
+            }
+        }
+    }
+
+    /// Number of rooms with at least one subscriber still registered.
+    pub fn active_count(&self) -> usize {
+        self.sessions.lock().expect("session registry lock poisoned").len()
+    }
+}
+
+impl Default for SessionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Registers `room_id` as an active session for the lifetime of this guard
+/// and deregisters it on drop, so a subscription stream that's dropped
+/// (client disconnect, panic, whatever) without running to completion can't
+/// leak an entry that would keep a graceful shutdown waiting forever.
+pub struct SessionGuard {
+    sessions: SessionRegistry,
+    room_id: String,
+}
+
+impl SessionGuard {
+    pub fn new(sessions: SessionRegistry, room_id: String) -> Self {
+        sessions.register(&room_id);
+        Self { sessions, room_id }
+    }
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        self.sessions.deregister(&self.room_id);
+    }
+}
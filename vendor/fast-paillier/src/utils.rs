@@ -1,7 +1,11 @@
 //! Various utilities
 
 use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::Mutex;
 
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::SeedableRng;
 use rand_core::RngCore;
 use rug::{Assign, Complete, Integer};
 
@@ -49,11 +53,96 @@ pub fn sample_in_mult_group(rng: &mut impl RngCore, n: &Integer) -> Integer {
     }
 }
 
+/// How many candidate-sized chunks [`sample_in_mult_group_batch`] draws from
+/// the RNG per buffer refill.
+const BATCH_SAMPLE_CHUNK: usize = 128;
+
+/// Same distribution as calling [`sample_in_mult_group`] `count` times, but
+/// amortizes the per-call `external_rand` setup and large-number GCD
+/// overhead across the whole batch: instead of spinning up a fresh
+/// `ThreadRandState` and rejection-looping one element at a time, it fills a
+/// reusable byte buffer holding [`BATCH_SAMPLE_CHUNK`] candidates' worth of
+/// random bytes from a single `rng.fill_bytes` call, reads each candidate as
+/// an `n.significant_bits()`-bit integer, and rejection-tests it with
+/// [`in_mult_group`]; the buffer is only refilled once every candidate in it
+/// has been consumed. Mirrors the buffered-PRNG field-element generation
+/// pattern used by prio's `prng` module.
+pub fn sample_in_mult_group_batch(rng: &mut impl RngCore, n: &Integer, count: usize) -> Vec<Integer> {
+    use rug::integer::Order;
+
+    let candidate_bits = n.significant_bits();
+    let candidate_bytes = candidate_bits.div_ceil(8) as usize;
+    let mut buf = vec![0u8; candidate_bytes * BATCH_SAMPLE_CHUNK];
+
+    let mut results = Vec::with_capacity(count);
+    let mut pos = buf.len();
+
+    while results.len() < count {
+        if pos >= buf.len() {
+            rng.fill_bytes(&mut buf);
+            pos = 0;
+        }
+
+        let mut candidate = Integer::from_digits(&buf[pos..pos + candidate_bytes], Order::Msf);
+        pos += candidate_bytes;
+
+        // `candidate_bytes` rounds the bit length up to a whole byte, so
+        // mask off the extra high bits instead of rejecting every draw
+        // whose top byte happens to be large.
+        candidate.keep_bits_mut(candidate_bits);
+
+        if in_mult_group(&candidate, n) {
+            results.push(candidate);
+        }
+    }
+
+    results
+}
+
 /// Generates a random safe prime
 pub fn generate_safe_prime(rng: &mut impl RngCore, bits: u32) -> Integer {
     sieve_generate_safe_primes(rng, bits, 300_000)
 }
 
+/// Deterministic wrapper around a seeded [`ChaCha20Rng`] for byte-for-byte
+/// reproducible safe-prime and Z*_n sampling, so known-answer test vectors
+/// for the paillier/cggmp21 protocols stay stable across machines and `rug`
+/// versions instead of depending on whatever `RngCore` the caller happened
+/// to pass in.
+///
+/// Reproducibility holds because [`Self::generate_safe_prime`] and
+/// [`Self::sample_in_mult_group`] draw from the same `ChaCha20Rng` stream in
+/// the exact order [`generate_safe_prime`] and [`sample_in_mult_group`]
+/// always draw in: the sieve consumes one `bits - 1`-bit `random_bits` draw
+/// per window restart and nothing else, and the rejection sampler consumes
+/// one `n.random_below_ref` draw per rejected candidate and nothing else.
+/// Neither draws additional randomness depending on the *value* sampled, so
+/// pinning the seed pins the entire output sequence. Treat this
+/// bit-consumption order as a stability guarantee: changing it changes every
+/// pinned KAT fixture.
+pub struct DeterministicPrimeGen {
+    rng: ChaCha20Rng,
+}
+
+impl DeterministicPrimeGen {
+    /// Builds a generator whose output is fully determined by `seed`.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self { rng: ChaCha20Rng::from_seed(seed) }
+    }
+
+    /// Same as [`generate_safe_prime`], drawing from this generator's
+    /// stream instead of an ad hoc RNG.
+    pub fn generate_safe_prime(&mut self, bits: u32) -> Integer {
+        generate_safe_prime(&mut self.rng, bits)
+    }
+
+    /// Same as [`sample_in_mult_group`], drawing from this generator's
+    /// stream instead of an ad hoc RNG.
+    pub fn sample_in_mult_group(&mut self, n: &Integer) -> Integer {
+        sample_in_mult_group(&mut self.rng, n)
+    }
+}
+
 /// Generate a random safe prime with a given sieve parameter.
 ///
 /// For different bit sizes, different parameter value will give fastest
@@ -62,6 +151,22 @@ pub fn generate_safe_prime(rng: &mut impl RngCore, bits: u32) -> Integer {
 /// [`generate_safe_prime`] is indistinguishable from optimal for 500-1700 bit
 /// lengths.
 pub fn sieve_generate_safe_primes(rng: &mut impl RngCore, bits: u32, amount: usize) -> Integer {
+    sieve_generate_safe_primes_until(rng, bits, amount, || false)
+        .expect("sieve never gives up when the stop closure always returns false")
+}
+
+/// Same sieve as [`sieve_generate_safe_primes`], but checked once per sieve
+/// window against `should_stop`, returning `None` as soon as it does instead
+/// of continuing to the next window. Shared by the single-threaded path
+/// (which passes a closure that never stops) and
+/// [`sieve_generate_safe_primes_parallel`] (where it's how a worker notices
+/// a sibling already won the race).
+fn sieve_generate_safe_primes_until(
+    rng: &mut impl RngCore,
+    bits: u32,
+    amount: usize,
+    mut should_stop: impl FnMut() -> bool,
+) -> Option<Integer> {
     use rug::integer::IsPrime;
 
     let amount = amount.min(small_primes::SMALL_PRIMES.len());
@@ -83,6 +188,10 @@ pub fn sieve_generate_safe_primes(rng: &mut impl RngCore, bits: u32, amount: usi
     let mut need_init = true;
 
     loop {
+        if should_stop() {
+            return None;
+        }
+
         if need_init {
             // generate an odd number of length `bits - 2`
             base.assign(Integer::random_bits(bits - 1, &mut rng));
@@ -155,7 +264,7 @@ pub fn sieve_generate_safe_primes(rng: &mut impl RngCore, bits: u32, amount: usi
                     safe_prime <<= 1;
                     safe_prime += 1;
                     if let IsPrime::Yes | IsPrime::Probably = safe_prime.is_probably_prime(25) {
-                        return safe_prime;
+                        return Some(safe_prime);
                     }
                 }
             }
@@ -172,6 +281,78 @@ pub fn sieve_generate_safe_primes(rng: &mut impl RngCore, bits: u32, amount: usi
     }
 }
 
+/// Generates a random safe prime the same way [`generate_safe_prime`] does,
+/// but races `threads` worker threads against each other instead of sieving
+/// single-threaded; see [`sieve_generate_safe_primes_parallel`] for how the
+/// workers are seeded and coordinated.
+pub fn generate_safe_prime_parallel(rng: &mut impl RngCore, bits: u32, threads: usize) -> Integer {
+    sieve_generate_safe_primes_parallel(rng, bits, 300_000, threads)
+}
+
+/// Parallel counterpart to [`sieve_generate_safe_primes`]. Spawns `threads`
+/// worker threads, each sieving its own disjoint windows via
+/// [`sieve_generate_safe_primes_until`], and returns the first candidate to
+/// pass both `is_probably_prime(25)` checks (the candidate and its safe
+/// prime `2q+1`).
+///
+/// Workers don't share or lock an RNG: a single 32-byte seed is drawn from
+/// `rng` up front, and each worker builds its own [`ChaCha20Rng`] from that
+/// seed, then calls [`ChaCha20Rng::set_stream`] keyed by its worker index so
+/// every worker draws from a distinct, non-overlapping keystream. The first
+/// worker to find a result flips a shared atomic flag that the others poll
+/// once per sieve window, so the losers stop promptly instead of sieving to
+/// completion.
+///
+/// `threads <= 1` falls back to [`sieve_generate_safe_primes`] directly, so
+/// callers can thread a configured worker count straight through without a
+/// special case. This parallel path spawns OS threads and is opt-in: the
+/// single-threaded sieve remains what [`generate_safe_prime`] uses, so
+/// no-std/wasm targets that can't spawn threads are unaffected.
+pub fn sieve_generate_safe_primes_parallel(
+    rng: &mut impl RngCore,
+    bits: u32,
+    amount: usize,
+    threads: usize,
+) -> Integer {
+    if threads <= 1 {
+        return sieve_generate_safe_primes(rng, bits, amount);
+    }
+
+    let mut seed = [0u8; 32];
+    rng.fill_bytes(&mut seed);
+
+    let stop = AtomicBool::new(false);
+    let result: Mutex<Option<Integer>> = Mutex::new(None);
+
+    std::thread::scope(|scope| {
+        for worker in 0..threads {
+            let stop = &stop;
+            let result = &result;
+            scope.spawn(move || {
+                let mut worker_rng = ChaCha20Rng::from_seed(seed);
+                worker_rng.set_stream(worker as u64);
+
+                let found = sieve_generate_safe_primes_until(&mut worker_rng, bits, amount, || {
+                    stop.load(AtomicOrdering::Relaxed)
+                });
+
+                if let Some(safe_prime) = found {
+                    let mut result = result.lock().expect("safe prime result lock poisoned");
+                    if result.is_none() {
+                        *result = Some(safe_prime);
+                    }
+                    stop.store(true, AtomicOrdering::Relaxed);
+                }
+            });
+        }
+    });
+
+    result
+        .into_inner()
+        .expect("safe prime result lock poisoned")
+        .expect("at least one worker finds a safe prime before every worker observes the stop flag")
+}
+
 /// Faster algorithm for modular exponentiation based on Chinese remainder theorem when modulo factorization is known
 ///
 /// `CrtExp` makes exponentation modulo `n` faster when factorization `n = n1 * n2` is known as well as `phi(n1)` and `phi(n2)`
@@ -282,6 +463,159 @@ impl CrtExp {
             Some(result)
         }
     }
+
+    /// Computes `bases[0]^exps[0] * bases[1]^exps[1] * ... mod n` in one
+    /// pass, using Straus's (a.k.a. Shamir's) trick for simultaneous
+    /// multi-exponentiation instead of calling [`Self::exp`] once per base
+    /// and multiplying the results together: that would pay for a full
+    /// exponentiation per base, where this shares a single squaring chain
+    /// across all of them. Intended for the `g^e1 * h^e2 mod n` products
+    /// that Paillier range/equality ZK proofs compute repeatedly.
+    ///
+    /// Precomputes, independently modulo `n1` and modulo `n2`, a table of
+    /// every subset-product of the (already-reduced) bases — `2^bases.len()`
+    /// entries — then scans the exponent bits from most to least
+    /// significant, squaring the running accumulator once per bit and
+    /// multiplying in the table entry selected by that bit's column across
+    /// every exponent. The two per-modulus results are recombined via
+    /// `beta` exactly as [`Self::exp`] does.
+    ///
+    /// `bases` and `exps` must be the same non-empty length, no longer than
+    /// 16, and every exponent must share the same sign (all from
+    /// [`Self::prepare_exponent`] on a non-negative value, or all on a
+    /// negative one); anything else returns `None`. The 16 bound (unlike the
+    /// sign/length checks) isn't a correctness requirement - the table a
+    /// larger `bases` would need still fits a `usize` mask well past that -
+    /// it's a memory guard: [`Self::multiexp_mod`] allocates `2^bases.len()`
+    /// `Integer`s for its subset table, so an unbounded `bases.len()` is an
+    /// easy OOM. 16 keeps the table at 65536 entries (this is only ever
+    /// called with 2 bases in practice - the `g^e1 * h^e2 mod n` products
+    /// Paillier range/equality ZK proofs compute) while leaving generous
+    /// headroom.
+    pub fn multiexp(&self, bases: &[Integer], exps: &[Exponent]) -> Option<Integer> {
+        if bases.is_empty() || bases.len() != exps.len() || bases.len() > 16 {
+            return None;
+        }
+
+        let is_negative = exps[0].is_negative;
+        if exps.iter().any(|e| e.is_negative != is_negative) {
+            return None;
+        }
+
+        let r1 = Self::multiexp_mod(bases, exps, &self.n1, |e| &e.e_mod_phi_pp);
+        let r2 = Self::multiexp_mod(bases, exps, &self.n2, |e| &e.e_mod_phi_qq);
+
+        let result = ((r2 - &r1) * &self.beta).modulo(&self.n2) * &self.n1 + &r1;
+
+        if is_negative {
+            result.invert(&self.n).ok()
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Straus's-trick simultaneous multi-exponentiation modulo a single CRT
+    /// factor `m` (`self.n1` or `self.n2`), reading each exponent's
+    /// `m`-reduced half via `select` (`e_mod_phi_pp` or `e_mod_phi_qq`).
+    fn multiexp_mod(
+        bases: &[Integer],
+        exps: &[Exponent],
+        m: &Integer,
+        select: impl Fn(&Exponent) -> &Integer,
+    ) -> Integer {
+        let k = bases.len();
+        let reduced_bases: Vec<Integer> = bases.iter().map(|b| b.modulo_ref(m).complete()).collect();
+
+        // table[mask] = product over every bit `i` set in `mask` of
+        // `reduced_bases[i]`, mod `m`; built bottom-up off the lowest set
+        // bit so each entry costs one multiply from an already-computed one.
+        let mut table = vec![Integer::from(1u8); 1usize << k];
+        for mask in 1usize..(1usize << k) {
+            let lowest_bit = mask.trailing_zeros() as usize;
+            let rest = mask & !(1 << lowest_bit);
+            table[mask] = (&table[rest] * &reduced_bases[lowest_bit]).complete().modulo(m);
+        }
+
+        let max_bits = exps.iter().map(|e| select(e).significant_bits()).max().unwrap_or(0);
+
+        let mut acc = Integer::from(1u8);
+        for bit in (0..max_bits).rev() {
+            acc = acc.square_ref().complete().modulo(m);
+
+            let mut column = 0usize;
+            for (i, e) in exps.iter().enumerate() {
+                if select(e).get_bit(bit) {
+                    column |= 1 << i;
+                }
+            }
+            if column != 0 {
+                acc = (&acc * &table[column]).complete().modulo(m);
+            }
+        }
+
+        acc
+    }
+
+    /// Blinded variant of [`Self::exp`] for contexts where this exponentiation's
+    /// running time or memory-access pattern could leak the secret
+    /// factorization or exponent through a timing/cache side channel, e.g.
+    /// because the host also serves attacker-influenced inputs. The
+    /// unblinded [`Self::exp`] stays available for contexts where `x` and
+    /// `e` are trusted and the blinding overhead isn't worth paying.
+    ///
+    /// Two independent countermeasures, both re-randomized on every call:
+    ///
+    /// - **Exponent blinding.** Each reduced exponent half is padded with a
+    ///   random multiple of its modulus's totient before the real work
+    ///   happens: `e_mod_phi_pp + k1 * phi_n1`, similarly for `phi_n2` with
+    ///   a fresh `k2`. Euler's theorem guarantees this doesn't change the
+    ///   result for any base coprime to that modulus, while fixing the
+    ///   scanned bit-length regardless of the true (secret) exponent size.
+    /// - **Base blinding.** A fresh `r` is drawn from Z*_n and raised to the
+    ///   Paillier-style mask `rho = r^n mod n`; `x * rho mod n` is
+    ///   exponentiated instead of `x` directly, so the value actually fed
+    ///   into the modular exponentiation is randomized every call. The true
+    ///   result is recovered by also exponentiating `rho` (with the same
+    ///   blinded exponent) and dividing it back out of the masked result:
+    ///   `(x * rho)^e / rho^e = x^e mod n`. This holds for any exponent, not
+    ///   just the case `rho`'s `r^n` shape is tailored for.
+    ///
+    /// `rug::Integer` doesn't implement `zeroize::Zeroize`, so every
+    /// intermediate blinding factor is cleared with a plain `assign(0)`
+    /// before it's dropped instead — best-effort hygiene for the secret
+    /// material in the spirit of what fuel-crypto does with `Zeroize`,
+    /// within what this dependency set actually supports.
+    pub fn exp_blinded(&self, x: &Integer, e: &Exponent, rng: &mut impl RngCore) -> Option<Integer> {
+        const BLIND_BITS: u32 = 64;
+
+        fn clear(x: &mut Integer) {
+            x.assign(0);
+        }
+
+        let r = sample_in_mult_group(rng, &self.n);
+        let mut rho = r.pow_mod(&self.n, &self.n).ok()?;
+
+        let mut k1 = Integer::from(Integer::random_bits(BLIND_BITS, &mut external_rand(rng)));
+        let mut k2 = Integer::from(Integer::random_bits(BLIND_BITS, &mut external_rand(rng)));
+        let blinded = Exponent {
+            e_mod_phi_pp: (&k1 * &self.phi_n1).complete() + &e.e_mod_phi_pp,
+            e_mod_phi_qq: (&k2 * &self.phi_n2).complete() + &e.e_mod_phi_qq,
+            is_negative: e.is_negative,
+        };
+        clear(&mut k1);
+        clear(&mut k2);
+
+        let mut blinded_x = (x * &rho).complete().modulo(&self.n);
+        let masked_result = self.exp(&blinded_x, &blinded)?;
+        clear(&mut blinded_x);
+
+        let rho_to_e = self.exp(&rho, &blinded)?;
+        clear(&mut rho);
+
+        let rho_to_e_inv = rho_to_e.invert(&self.n).ok()?;
+
+        Some((&masked_result * &rho_to_e_inv).complete().modulo(&self.n))
+    }
 }
 
 impl fmt::Debug for CrtExp {
@@ -315,6 +649,100 @@ mod test {
         }
     }
 
+    /// Fixed-seed reproducibility KAT: two independently constructed
+    /// generators seeded with the same bytes must draw the exact same safe
+    /// prime and the exact same Z*_n sample, and a generator seeded
+    /// differently must not - pinning the bit-consumption-order guarantee
+    /// [`super::DeterministicPrimeGen`]'s doc comment promises.
+    #[test]
+    fn deterministic_prime_gen_is_reproducible_for_a_fixed_seed() {
+        use super::DeterministicPrimeGen;
+
+        let seed = [7u8; 32];
+        let mut gen_a = DeterministicPrimeGen::from_seed(seed);
+        let mut gen_b = DeterministicPrimeGen::from_seed(seed);
+
+        let prime_a = gen_a.generate_safe_prime(256);
+        let prime_b = gen_b.generate_safe_prime(256);
+        assert_eq!(prime_a, prime_b, "same seed must yield the same safe prime");
+
+        let sample_a = gen_a.sample_in_mult_group(&prime_a);
+        let sample_b = gen_b.sample_in_mult_group(&prime_b);
+        assert_eq!(sample_a, sample_b, "same seed must yield the same Z*_n sample after the same draws");
+
+        let mut gen_c = DeterministicPrimeGen::from_seed([9u8; 32]);
+        let prime_c = gen_c.generate_safe_prime(256);
+        assert_ne!(prime_a, prime_c, "a different seed must (overwhelmingly likely) yield a different safe prime");
+    }
+
+    /// `sample_in_mult_group_batch` must hand back exactly `count` elements,
+    /// every one actually in Z*_n, matching the one-at-a-time contract of
+    /// [`super::sample_in_mult_group`] it's meant to be a drop-in
+    /// replacement for.
+    #[test]
+    fn sample_in_mult_group_batch_returns_count_elements_all_in_the_group() {
+        use super::{in_mult_group, sample_in_mult_group_batch};
+
+        let mut rng = rand_dev::DevRng::new();
+        let n = rug::Integer::from(1_000_003u32);
+
+        let samples = sample_in_mult_group_batch(&mut rng, &n, 64);
+        assert_eq!(samples.len(), 64);
+        for sample in &samples {
+            assert!(in_mult_group(sample, &n));
+        }
+    }
+
+    /// `CrtExp::multiexp(bases, exps)` must agree with multiplying together
+    /// the result of calling `CrtExp::exp` on each `(base, exp)` pair
+    /// individually - the whole point of Straus's trick is to compute the
+    /// same product faster, not a different one.
+    #[test]
+    fn multiexp_agrees_with_separate_exp_calls_multiplied_together() {
+        use super::CrtExp;
+
+        let p = rug::Integer::from(61u32);
+        let q = rug::Integer::from(53u32);
+        let crt = CrtExp::build_n(&p, &q).expect("61 and 53 are prime");
+
+        let bases = [rug::Integer::from(7u32), rug::Integer::from(11u32), rug::Integer::from(13u32)];
+        let exps = [rug::Integer::from(17u32), rug::Integer::from(19u32), rug::Integer::from(23u32)];
+        let prepared: Vec<_> = exps.iter().map(|e| crt.prepare_exponent(e)).collect();
+
+        let expected = bases
+            .iter()
+            .zip(&prepared)
+            .map(|(base, exp)| crt.exp(base, exp).unwrap())
+            .fold(rug::Integer::from(1u8), |acc, r| (acc * r).complete().modulo(&(&p * &q).complete()));
+
+        let actual = crt.multiexp(&bases, &prepared).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    /// `CrtExp::exp_blinded` re-randomizes the base and exponent on every
+    /// call, but must still unblind back to exactly the same value
+    /// [`CrtExp::exp`] computes - the blinding must be invisible to the
+    /// result, only to anyone watching the computation's timing/memory
+    /// access.
+    #[test]
+    fn exp_blinded_agrees_with_exp() {
+        use super::CrtExp;
+
+        let mut rng = rand_dev::DevRng::new();
+        let p = rug::Integer::from(61u32);
+        let q = rug::Integer::from(53u32);
+        let crt = CrtExp::build_n(&p, &q).expect("61 and 53 are prime");
+
+        let x = rug::Integer::from(12345u32);
+        let e = crt.prepare_exponent(&rug::Integer::from(777u32));
+
+        let expected = crt.exp(&x, &e).unwrap();
+        for _ in 0..5 {
+            let blinded = crt.exp_blinded(&x, &e, &mut rng).unwrap();
+            assert_eq!(blinded, expected, "blinded exponentiation must unblind to the same result every call");
+        }
+    }
+
     #[test]
     fn mult_group_check() {
         use super::{in_mult_group, in_mult_group_abs};